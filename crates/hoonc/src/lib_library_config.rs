@@ -4,10 +4,12 @@ use mimalloc::MiMalloc;
 static GLOBAL: MiMalloc = MiMalloc;
 
 pub mod memory_pool;
+pub mod merkle;
 pub mod streaming_prover;
 
 // Re-export main functions
 pub use memory_pool::init_memory_pools;
+pub use merkle::{verify, MerkleAccumulator};
 pub use streaming_prover::{StreamingProver, report_memory_usage};
 
 /// Initialize the optimized miner library
@@ -1,42 +1,104 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use mimalloc::MiMalloc;
+use zkvm_jetpack::form::math::tip5::DIGEST_LENGTH;
 
 #[global_allocator]
 static GLOBAL: MiMalloc = MiMalloc;
 
 mod memory_pool;
+mod pow;
+
+/// The fields a block header commits to, flattened into the `u64` words
+/// `hash_varlen_plain` hashes alongside the nonce. This binary has no node
+/// connection to pull a real parent digest or chain height from, so it
+/// always mines against the genesis header (`height = 0`, all-zero parent
+/// digest) - a real miner wires these fields in from the chain state it's
+/// extending instead of hardcoding them.
+struct BlockHeaderPreimage {
+    height: u64,
+    timestamp: u64,
+    compact_target: u32,
+    parent_digest: [u64; DIGEST_LENGTH],
+}
+
+impl BlockHeaderPreimage {
+    fn genesis(compact_target: u32) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock is before the Unix epoch")
+            .as_secs();
+
+        BlockHeaderPreimage {
+            height: 0,
+            timestamp,
+            compact_target,
+            parent_digest: [0u64; DIGEST_LENGTH],
+        }
+    }
+
+    /// Flattens the header into the word sequence `hash_varlen_plain` hashes:
+    /// `height`, `timestamp`, `compact_target`, then the parent digest limbs.
+    fn to_words(&self) -> Vec<u64> {
+        let mut words = Vec::with_capacity(3 + DIGEST_LENGTH);
+        words.push(self.height);
+        words.push(self.timestamp);
+        words.push(self.compact_target as u64);
+        words.extend_from_slice(&self.parent_digest);
+        words
+    }
+}
 
 fn main() {
     println!("Nockchain Optimized Miner v0.1.0");
-    
+
     // Initialize memory pools
     memory_pool::init_memory_pools();
-    
+
     // Set process priority (optional, requires root on Linux)
     #[cfg(target_os = "linux")]
     unsafe {
         libc::nice(-10); // Higher priority
     }
-    
-    // Get the existing miner code and run it
-    // For now, this is a placeholder - you'll integrate with existing code
+
     run_existing_miner();
 }
 
 fn run_existing_miner() {
-    // This is where you'll call your existing miner code
-    // For now, let's add a simple test to verify memory pool works
-    
-    println!("Testing memory pool...");
-    
-    // Allocate and deallocate to test pool
-    for i in 0..100 {
-        let mut vec = memory_pool::PooledVec::new(1024);
-        vec.as_mut_slice()[0] = i;
-        // vec automatically returned to pool when dropped
+    // Bitcoin-`nBits`-style compact target: exponent 0x29 with mantissa
+    // 0x00ffff is what `compact_from_target` gives for the all-ones 320-bit
+    // target, i.e. the loosest one. It decodes to a top limb of
+    // 0xffff000000000000, so nearly every digest meets it - fine for
+    // smoke-testing the loop against real hardware before a node-supplied
+    // target exists.
+    let compact_target: u32 = 0x2900ffff;
+    let target = pow::target_from_compact(compact_target);
+
+    println!(
+        "Mining against compact target {:#010x} ({:?})",
+        compact_target, target
+    );
+
+    let header_preimage = BlockHeaderPreimage::genesis(compact_target).to_words();
+
+    let mut nonce: u64 = 0;
+    loop {
+        // Per-attempt scratch buffer comes from the same pool the rest of
+        // the miner uses, rather than a fresh heap allocation every nonce.
+        let mut attempt = memory_pool::PooledVec::new(header_preimage.len() + 1);
+        attempt.as_mut_slice()[..header_preimage.len()].copy_from_slice(&header_preimage);
+        attempt.as_mut_slice()[header_preimage.len()] = nonce;
+
+        let digest = zkvm_jetpack::jets::tip5_jets::hash_varlen_plain(attempt.as_slice());
+
+        if pow::meets_target(&digest, &target) {
+            println!("Found a solution at nonce {nonce}: {digest:?}");
+            break;
+        }
+
+        nonce = nonce.wrapping_add(1);
+        if nonce % 1_000_000 == 0 {
+            println!("{nonce} nonces tried, no solution yet");
+        }
     }
-    
-    println!("Memory pool test complete");
-    
-    // TODO: Call actual miner code here
-    // nockchain::run_miner();
 }
@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::sync::Mutex;
 use std::collections::VecDeque;
 use bumpalo::Bump;
@@ -7,7 +8,18 @@ lazy_static::lazy_static! {
     static ref SMALL_POOL: Mutex<VecDeque<Vec<u64>>> = Mutex::new(VecDeque::new());
     static ref MEDIUM_POOL: Mutex<VecDeque<Vec<u64>>> = Mutex::new(VecDeque::new());
     static ref LARGE_POOL: Mutex<VecDeque<Vec<u64>>> = Mutex::new(VecDeque::new());
-    static ref BUMP_ALLOCATOR: Mutex<Bump> = Mutex::new(Bump::with_capacity(1 << 28)); // 256MB
+}
+
+thread_local! {
+    // One arena per thread instead of one global `Mutex<Bump>`. The old
+    // scheme handed out a `&'a Bump` that outlived the `MutexGuard` it was
+    // borrowed from (the guard dropped at the end of the `let` statement
+    // that created it, via a raw-pointer cast to launder the lifetime) —
+    // so a second thread could lock the mutex and call `reset()` while the
+    // first thread's "borrow" was still in use, a data race on the same
+    // `Bump`. A thread-local arena is only ever touched by the thread that
+    // owns it, so there's no concurrent access to race with.
+    static BUMP_ALLOCATOR: RefCell<Bump> = RefCell::new(Bump::with_capacity(1 << 28)); // 256MB
 }
 
 pub struct PooledVec {
@@ -91,25 +103,56 @@ impl Drop for PooledVec {
     }
 }
 
-// Bump allocator for temporary allocations
-pub struct TempAllocator<'a> {
-    bump: &'a Bump,
+// Bump allocator for temporary allocations, backed by the thread-local
+// arena above. Scratch buffers handed out by one invocation are expected to
+// be done with by the time `reset()` runs for that thread (typically once
+// per jet call, after its transform has copied its result elsewhere) -
+// `reset()` only affects the calling thread's arena, so this never races
+// with another thread's in-flight allocations the way the old
+// `Mutex<Bump>` scheme could.
+pub struct TempAllocator {
+    _private: (),
 }
 
-impl<'a> TempAllocator<'a> {
+impl TempAllocator {
     pub fn new() -> Self {
-        let bump = &*BUMP_ALLOCATOR.lock().unwrap();
-        // Safety: We're careful to not hold this reference across allocations
-        let bump = unsafe { &*(bump as *const Bump) };
-        TempAllocator { bump }
+        TempAllocator { _private: () }
     }
 
-    pub fn alloc_slice(&self, size: usize) -> &'a mut [u64] {
-        self.bump.alloc_slice_fill_copy(size, 0u64)
+    /// The returned slice's lifetime is tied to `&self`, not `'static`: the
+    /// arena itself outlives any one `TempAllocator` handle, but the
+    /// borrow checker only has a lifetime to enforce if we give it one.
+    /// Pinning it to `&self` is what lets `reset` (below) require `&mut
+    /// self` and have that mean something - a slice still borrowed from
+    /// this handle makes `reset` a compile error instead of a same-thread
+    /// use-after-free.
+    pub fn alloc_slice(&self, size: usize) -> &mut [u64] {
+        BUMP_ALLOCATOR.with(|bump| {
+            let slice = bump.borrow().alloc_slice_fill_copy(size, 0u64);
+            // Safety: the thread-local arena lives for the life of this
+            // thread and is never moved, so a reference into it is valid
+            // for as long as the thread runs and `reset()` isn't called
+            // while it's in use. Transmuting to `'static` here only
+            // escapes the short borrow of the `.with()` closure; the
+            // function's elided return lifetime then narrows it back down
+            // to `&self`'s, which is the lifetime callers actually see.
+            unsafe { std::mem::transmute::<&mut [u64], &'static mut [u64]>(slice) }
+        })
     }
 
-    pub fn reset() {
-        BUMP_ALLOCATOR.lock().unwrap().reset();
+    /// Resets the thread-local arena, invalidating every slice previously
+    /// handed out by `alloc_slice` on this thread. Takes `&mut self`
+    /// instead of being a bare associated function so the borrow checker
+    /// rejects calling this while a slice borrowed from this handle (via
+    /// `alloc_slice`, which borrows `&self`) is still live.
+    pub fn reset(&mut self) {
+        BUMP_ALLOCATOR.with(|bump| bump.borrow_mut().reset());
+    }
+}
+
+impl Default for TempAllocator {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -0,0 +1,199 @@
+//! Append-only Merkle commitment over streamed `u64` leaves.
+//!
+//! Built for `StreamingProver::build_table_committed`, where table columns
+//! arrive chunk by chunk and we want a running commitment without ever
+//! materializing the full tree. Leaves are folded into a frontier of
+//! subtree roots indexed by height (one cached root per set bit of the
+//! running leaf count), the same carry-propagation shape as incrementing a
+//! binary counter: appending a leaf combines equal-height roots
+//! (`hash(left, right)`) until no two heights collide.
+//!
+//! Nodes are Tip5 digests (`hash_varlen_plain`, the same hash the miner loop
+//! in `main_optimised_entry_point.rs` uses for the PoW digest that `pow.rs`
+//! checks), not `std`'s `DefaultHasher`: `DefaultHasher` is
+//! SipHash, which the standard library explicitly documents as unspecified
+//! and subject to change across Rust versions, and which was never designed
+//! for collision resistance in the first place - neither property a Merkle
+//! commitment can do without.
+
+use zkvm_jetpack::form::math::tip5::DIGEST_LENGTH;
+use zkvm_jetpack::jets::tip5_jets::hash_varlen_plain;
+
+/// A Merkle node: a full Tip5 digest, the same shape `pow.rs`'s `Target`
+/// uses for its hash output.
+pub type Digest = [u64; DIGEST_LENGTH];
+
+fn hash_leaf(leaf: u64) -> Digest {
+    // domain-separate leaves (tag 0) from internal pairs (tag 1); the leaf
+    // goes in as two 32-bit halves so every input word is a base-field
+    // element (< p), which `hash_varlen_plain` asserts, whatever its value
+    hash_varlen_plain(&[0u64, leaf >> 32, leaf & 0xFFFF_FFFF])
+}
+
+fn hash_pair(left: Digest, right: Digest) -> Digest {
+    let mut input = Vec::with_capacity(1 + 2 * DIGEST_LENGTH);
+    input.push(1u64);
+    input.extend_from_slice(&left);
+    input.extend_from_slice(&right);
+    hash_varlen_plain(&input)
+}
+
+/// A single step of an inclusion proof: the sibling hash and whether that
+/// sibling sits to the right of the node being combined (so the node itself
+/// is the left operand of `hash_pair`).
+pub type ProofStep = (Digest, bool);
+
+/// Incremental Merkle accumulator. Holds only the frontier of completed
+/// subtree roots (`O(log n)` entries) plus the raw leaves needed to
+/// reconstruct inclusion proofs on demand.
+pub struct MerkleAccumulator {
+    leaves: Vec<u64>,
+    /// `frontier[h]` is the root of a completed height-`h` subtree waiting
+    /// to be combined with a sibling of the same height, or `None` if no
+    /// such subtree is pending (mirrors bit `h` of `leaves.len()`).
+    frontier: Vec<Option<Digest>>,
+}
+
+impl MerkleAccumulator {
+    pub fn new() -> Self {
+        MerkleAccumulator {
+            leaves: Vec::new(),
+            frontier: Vec::new(),
+        }
+    }
+
+    /// Fold one more leaf into the accumulator.
+    pub fn append(&mut self, leaf: u64) {
+        self.leaves.push(leaf);
+
+        let mut node = hash_leaf(leaf);
+        let mut height = 0usize;
+        loop {
+            if height == self.frontier.len() {
+                self.frontier.push(Some(node));
+                break;
+            }
+            match self.frontier[height].take() {
+                Some(sibling) => {
+                    node = hash_pair(sibling, node);
+                    height += 1;
+                }
+                None => {
+                    self.frontier[height] = Some(node);
+                    break;
+                }
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// The peaks of the frontier, from highest height (covering the oldest
+    /// leaves) to lowest (covering the most recent), alongside the leaf
+    /// offset each peak's subtree starts at.
+    fn peaks_desc(&self) -> Vec<(usize, Digest, usize)> {
+        let mut peaks = Vec::new();
+        let mut offset = 0usize;
+        for (height, slot) in self.frontier.iter().enumerate().rev() {
+            if let Some(hash) = slot {
+                peaks.push((height, *hash, offset));
+                offset += 1 << height;
+            }
+        }
+        peaks
+    }
+
+    /// The current commitment: all frontier peaks bagged together, smallest
+    /// peak outermost. Returns the all-zero digest for an empty accumulator.
+    pub fn root(&self) -> Digest {
+        let mut acc: Option<Digest> = None;
+        for slot in self.frontier.iter().rev() {
+            if let Some(h) = slot {
+                acc = Some(match acc {
+                    None => *h,
+                    Some(a) => hash_pair(*h, a),
+                });
+            }
+        }
+        acc.unwrap_or([0u64; DIGEST_LENGTH])
+    }
+
+    /// The sibling path proving `leaves[leaf_index]` is included under
+    /// `root()`. Proof length is `O(log n)`; verifying it only needs the
+    /// leaf value, its index, and this path.
+    pub fn prove(&self, leaf_index: usize) -> Option<Vec<ProofStep>> {
+        if leaf_index >= self.leaves.len() {
+            return None;
+        }
+
+        let peaks = self.peaks_desc();
+        let peak_pos = peaks
+            .iter()
+            .position(|&(height, _, offset)| {
+                leaf_index >= offset && leaf_index < offset + (1 << height)
+            })
+            .expect("leaf_index within range must fall inside exactly one peak");
+        let (height, _, offset) = peaks[peak_pos];
+        let local_index = leaf_index - offset;
+
+        let mut proof = Vec::new();
+
+        // Path inside the peak's own perfect subtree.
+        let mut level: Vec<Digest> = self.leaves[offset..offset + (1 << height)]
+            .iter()
+            .map(|&l| hash_leaf(l))
+            .collect();
+        let mut idx = local_index;
+        while level.len() > 1 {
+            let sibling_idx = idx ^ 1;
+            let is_right = idx % 2 == 0;
+            proof.push((level[sibling_idx], is_right));
+
+            let mut next = Vec::with_capacity(level.len() / 2);
+            for pair in level.chunks(2) {
+                next.push(hash_pair(pair[0], pair[1]));
+            }
+            level = next;
+            idx /= 2;
+        }
+
+        // Bagging steps: fold in the running accumulation of earlier
+        // (higher, older) peaks, then each later (smaller, newer) peak, in
+        // the exact order `root()` combines them.
+        if peak_pos > 0 {
+            let mut acc = peaks[0].1;
+            for &(_, hash, _) in &peaks[1..peak_pos] {
+                acc = hash_pair(hash, acc);
+            }
+            proof.push((acc, true));
+        }
+        for &(_, hash, _) in &peaks[peak_pos + 1..] {
+            proof.push((hash, false));
+        }
+
+        Some(proof)
+    }
+}
+
+impl Default for MerkleAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Verifies `proof` shows `leaf` at `leaf_index` is included under `root`.
+/// `leaf_index` only matters for locating the leaf before proving; the
+/// direction at each step is carried in `proof` itself.
+pub fn verify(root: Digest, leaf: u64, proof: &[ProofStep]) -> bool {
+    let mut acc = hash_leaf(leaf);
+    for &(sibling, sibling_is_right) in proof {
+        acc = if sibling_is_right {
+            hash_pair(acc, sibling)
+        } else {
+            hash_pair(sibling, acc)
+        };
+    }
+    acc == root
+}
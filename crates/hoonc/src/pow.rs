@@ -0,0 +1,108 @@
+//! Compact (Bitcoin `nBits`-style) difficulty target encoding and the
+//! acceptance rule for the Tip5 proof-of-work digest.
+//!
+//! A compact target packs a 256-bit(ish) integer into 32 bits: the top byte
+//! is an exponent `E`, the low three bytes are a 24-bit mantissa `M`, and
+//! the decoded target is `M * 256^(E - 3)`. `E <= 3` instead right-shifts
+//! the mantissa so small targets aren't truncated to zero, and a mantissa
+//! with its sign bit (bit 23) set is rejected the same way Bitcoin treats
+//! a negative compact target as invalid.
+
+use zkvm_jetpack::form::math::tip5::DIGEST_LENGTH;
+
+/// A `hash_varlen` digest, or a difficulty target in the same shape,
+/// interpreted as a big-endian `64 * DIGEST_LENGTH`-bit integer (`target[0]`
+/// is the most significant limb).
+pub type Target = [u64; DIGEST_LENGTH];
+
+/// Decodes a compact (`nBits`-style) target into its full big-endian limb
+/// representation. Returns the zero target if `compact`'s mantissa has its
+/// sign bit set (mirrors Bitcoin rejecting a "negative" compact target).
+pub fn target_from_compact(compact: u32) -> Target {
+    let exponent = (compact >> 24) as i32;
+    let mantissa = compact & 0x007F_FFFF;
+
+    if compact & 0x0080_0000 != 0 {
+        return [0u64; DIGEST_LENGTH];
+    }
+
+    let mut target = [0u64; DIGEST_LENGTH];
+    let shift = (exponent - 3) * 8;
+    if shift >= 0 {
+        shift_left_into(&mut target, mantissa as u64, shift as u32);
+    } else {
+        let mantissa = (mantissa as u64) >> ((-shift).min(24) as u32);
+        target[DIGEST_LENGTH - 1] = mantissa;
+    }
+
+    target
+}
+
+/// Inverse of `target_from_compact`: keeps only as many of `target`'s most
+/// significant bits as fit the mantissa, so the encoding decodes to the
+/// largest representable value `<= target` (the usual "round down"
+/// convention for difficulty targets).
+pub fn compact_from_target(target: &Target) -> u32 {
+    let total_bits = DIGEST_LENGTH * 64;
+    let highest_bit = (0..total_bits)
+        .rev()
+        .find(|&bit| bit_at(target, bit))
+        .unwrap_or(0);
+
+    // `exponent` counts whole bytes, most-significant first, so the
+    // mantissa always starts within the top byte of the encoding.
+    let exponent = (highest_bit / 8) + 1;
+    let shift = (exponent as i32 - 3) * 8;
+
+    let mut mantissa: u32 = 0;
+    for i in 0..24u32 {
+        let bit = shift + i as i32;
+        if bit >= 0 && bit < (total_bits as i32) && bit_at(target, bit as usize) {
+            mantissa |= 1 << i;
+        }
+    }
+
+    // A mantissa with the sign bit set needs one more byte of exponent,
+    // shifted right, to stay non-negative (same rule `target_from_compact`
+    // enforces on decode).
+    if mantissa & 0x0080_0000 != 0 {
+        ((exponent as u32 + 1) << 24) | (mantissa >> 8)
+    } else {
+        ((exponent as u32) << 24) | mantissa
+    }
+}
+
+/// True if `digest` (interpreted as a big-endian integer) is `<= target`,
+/// i.e. the proof of work is acceptable.
+pub fn meets_target(digest: &Target, target: &Target) -> bool {
+    for i in 0..DIGEST_LENGTH {
+        if digest[i] != target[i] {
+            return digest[i] < target[i];
+        }
+    }
+    true
+}
+
+fn shift_left_into(target: &mut Target, mantissa: u64, shift: u32) {
+    let limb_shift = (shift / 64) as usize;
+    let bit_shift = shift % 64;
+
+    if limb_shift >= DIGEST_LENGTH {
+        return;
+    }
+    let dst = DIGEST_LENGTH - 1 - limb_shift;
+
+    if bit_shift == 0 {
+        target[dst] |= mantissa;
+    } else {
+        target[dst] |= mantissa << bit_shift;
+        if dst > 0 {
+            target[dst - 1] |= mantissa >> (64 - bit_shift);
+        }
+    }
+}
+
+fn bit_at(target: &Target, bit: usize) -> bool {
+    let limb = DIGEST_LENGTH - 1 - bit / 64;
+    (target[limb] >> (bit % 64)) & 1 == 1
+}
@@ -1,16 +1,36 @@
 use crate::memory_pool::{PooledVec, TempAllocator};
+use crate::merkle::MerkleAccumulator;
+use memmap2::{Mmap, MmapMut, MmapOptions};
+use std::fs::{File, OpenOptions};
 use std::mem;
+use tempfile::{tempdir, TempDir};
 
 const CHUNK_SIZE: usize = 4096; // Process tables in 4K row chunks
 
+/// Default for `StreamingProver::spill_threshold_bytes`: above this many
+/// total table bytes, `build_table_spilled` backs columns with
+/// memory-mapped temp files instead of growing them in RAM.
+const DEFAULT_SPILL_THRESHOLD_BYTES: usize = 256 * 1024 * 1024; // 256 MiB
+
 pub struct StreamingProver {
     chunk_size: usize,
+    spill_threshold_bytes: usize,
 }
 
 impl StreamingProver {
     pub fn new() -> Self {
         StreamingProver {
             chunk_size: CHUNK_SIZE,
+            spill_threshold_bytes: DEFAULT_SPILL_THRESHOLD_BYTES,
+        }
+    }
+
+    /// Like `new`, but with the `build_table_spilled` spill threshold set to
+    /// `spill_threshold_bytes` instead of the 256 MiB default.
+    pub fn with_spill_threshold(spill_threshold_bytes: usize) -> Self {
+        StreamingProver {
+            chunk_size: CHUNK_SIZE,
+            spill_threshold_bytes,
         }
     }
 
@@ -56,48 +76,219 @@ impl StreamingProver {
         result
     }
 
-    /// Memory-efficient polynomial interpolation
+    /// Like `build_table_streaming`, but additionally folds each column's
+    /// values into its own `MerkleAccumulator` as chunks are transposed, so
+    /// the caller gets a commitment to every column without a second pass
+    /// over the table (and without ever holding a full tree in memory).
+    pub fn build_table_committed<F>(
+        &self,
+        num_rows: usize,
+        num_cols: usize,
+        mut row_generator: F,
+    ) -> (Vec<Vec<u64>>, Vec<MerkleAccumulator>)
+    where
+        F: FnMut(usize) -> Vec<u64>,
+    {
+        let mut result = Vec::with_capacity(num_cols);
+        let mut commitments = Vec::with_capacity(num_cols);
+
+        for _ in 0..num_cols {
+            result.push(Vec::with_capacity(num_rows));
+            commitments.push(MerkleAccumulator::new());
+        }
+
+        for chunk_start in (0..num_rows).step_by(self.chunk_size) {
+            let chunk_end = (chunk_start + self.chunk_size).min(num_rows);
+
+            let mut chunk_data = Vec::with_capacity((chunk_end - chunk_start) * num_cols);
+            for row_idx in chunk_start..chunk_end {
+                let row = row_generator(row_idx);
+                chunk_data.extend(row);
+            }
+
+            for row in 0..(chunk_end - chunk_start) {
+                for col in 0..num_cols {
+                    let value = chunk_data[row * num_cols + col];
+                    result[col].push(value);
+                    commitments[col].append(value);
+                }
+            }
+
+            drop(chunk_data);
+        }
+
+        (result, commitments)
+    }
+
+    /// Out-of-core variant of `build_table_streaming`: below
+    /// `spill_threshold_bytes` this just delegates to the in-RAM path, but
+    /// once the table would exceed that budget, each column is backed by
+    /// its own memory-mapped temp file instead of a growing `Vec`, so
+    /// tables larger than physical memory can still be built. File setup
+    /// runs on a blocking task, the same way `mining_attempt` spins up its
+    /// temp directory off the async runtime thread.
+    pub async fn build_table_spilled<F>(
+        &self,
+        num_rows: usize,
+        num_cols: usize,
+        mut row_generator: F,
+    ) -> SpilledTable
+    where
+        F: FnMut(usize) -> Vec<u64>,
+    {
+        let total_bytes = num_rows
+            .saturating_mul(num_cols)
+            .saturating_mul(mem::size_of::<u64>());
+
+        if total_bytes <= self.spill_threshold_bytes {
+            let table = self.build_table_streaming(num_rows, num_cols, row_generator);
+            return SpilledTable {
+                columns: table.into_iter().map(SpilledColumn::InMemory).collect(),
+                _tempdir: None,
+            };
+        }
+
+        let dir = tokio::task::spawn_blocking(|| {
+            tempdir().expect("Failed to create temporary directory")
+        })
+        .await
+        .expect("tempdir task panicked");
+
+        let col_bytes = (num_rows * mem::size_of::<u64>()) as u64;
+        let dir_path = dir.path().to_path_buf();
+        let mut mapped: Vec<(File, MmapMut)> = tokio::task::spawn_blocking(move || {
+            (0..num_cols)
+                .map(|col| {
+                    let path = dir_path.join(format!("col-{col}.bin"));
+                    let file = OpenOptions::new()
+                        .read(true)
+                        .write(true)
+                        .create(true)
+                        .truncate(true)
+                        .open(&path)
+                        .expect("failed to create column spill file");
+                    file.set_len(col_bytes)
+                        .expect("failed to size column spill file");
+                    let mmap = unsafe {
+                        MmapOptions::new()
+                            .map_mut(&file)
+                            .expect("failed to mmap column spill file")
+                    };
+                    (file, mmap)
+                })
+                .collect()
+        })
+        .await
+        .expect("column spill setup task panicked");
+
+        for chunk_start in (0..num_rows).step_by(self.chunk_size) {
+            let chunk_end = (chunk_start + self.chunk_size).min(num_rows);
+
+            let mut chunk_data = Vec::with_capacity((chunk_end - chunk_start) * num_cols);
+            for row_idx in chunk_start..chunk_end {
+                let row = row_generator(row_idx);
+                chunk_data.extend(row);
+            }
+
+            for row in 0..(chunk_end - chunk_start) {
+                let global_row = chunk_start + row;
+                let byte_offset = global_row * mem::size_of::<u64>();
+                for col in 0..num_cols {
+                    let value = chunk_data[row * num_cols + col];
+                    let (_file, mmap) = &mut mapped[col];
+                    mmap[byte_offset..byte_offset + mem::size_of::<u64>()]
+                        .copy_from_slice(&value.to_ne_bytes());
+                }
+            }
+
+            drop(chunk_data);
+        }
+
+        let columns = mapped
+            .into_iter()
+            .map(|(file, mmap)| {
+                mmap.flush().expect("failed to flush column spill file");
+                SpilledColumn::Mapped {
+                    mmap: mmap.make_read_only().expect("failed to freeze column mapping"),
+                    _file: file,
+                }
+            })
+            .collect();
+
+        SpilledTable {
+            columns,
+            _tempdir: Some(dir),
+        }
+    }
+
+    /// Memory-efficient polynomial interpolation: an inverse NTT over the
+    /// Goldilocks field, so the result is the genuine coefficient vector of
+    /// the unique polynomial through `values` on the size-`domain_size`
+    /// subgroup of roots of unity.
     pub fn interpolate_streaming(&self, values: &[u64], domain_size: usize) -> PooledVec {
         // Use pooled vector for result
         let mut result = PooledVec::new(domain_size);
-        
-        // Use temporary allocator for intermediate values
-        let temp_alloc = TempAllocator::new();
-        let workspace = temp_alloc.alloc_slice(domain_size * 2);
-        
-        // Perform FFT in chunks to maintain cache locality
-        self.fft_chunked(values, result.as_mut_slice(), workspace);
-        
-        // Reset temporary allocator
-        TempAllocator::reset();
-        
+        result.as_mut_slice().copy_from_slice(&values[..domain_size]);
+
+        // Twiddle table lives in the bump arena and is reused across every
+        // chunked pass of this transform.
+        let mut temp_alloc = TempAllocator::new();
+        let twiddles = temp_alloc.alloc_slice(domain_size / 2);
+        fill_twiddle_table(twiddles, domain_size, true);
+
+        self.ntt_chunked(result.as_mut_slice(), twiddles);
+
+        // Inverse transform needs the final 1/n scaling.
+        let n_inv = ginv(domain_size as u64 % GOLDILOCKS_P);
+        for x in result.as_mut_slice().iter_mut() {
+            *x = gmul(*x, n_inv);
+        }
+
+        // `twiddles`'s last use was above, so this borrows `temp_alloc`
+        // exclusively only once nothing still holds the slice it handed out.
+        temp_alloc.reset();
+
         result
     }
 
-    /// Chunked FFT for better cache usage
-    fn fft_chunked(&self, input: &[u64], output: &mut [u64], workspace: &mut [u64]) {
+    /// Forward NTT, in place, chunked for cache locality.
+    pub fn fft_chunked(&self, input: &[u64], output: &mut [u64]) {
         let n = input.len();
-        
-        // Copy input to output
         output[..n].copy_from_slice(input);
-        
+
+        let mut temp_alloc = TempAllocator::new();
+        let twiddles = temp_alloc.alloc_slice(n / 2);
+        fill_twiddle_table(twiddles, n, false);
+
+        self.ntt_chunked(output, twiddles);
+
+        temp_alloc.reset();
+    }
+
+    /// Iterative, in-place radix-2 decimation-in-time NTT modulo the
+    /// Goldilocks prime. `twiddles` must hold `n/2` consecutive powers of
+    /// the appropriate (forward or inverse) primitive `n`-th root of unity,
+    /// as produced by `fill_twiddle_table`.
+    fn ntt_chunked(&self, data: &mut [u64], twiddles: &[u64]) {
+        let n = data.len();
+
         // Bit reversal with cache blocking
-        self.bit_reversal_blocked(output, n);
-        
-        // FFT with cache-aware passes
+        self.bit_reversal_blocked(data, n);
+
+        // Butterfly stages with cache-aware blocking
         let mut stride = 1;
         while stride < n {
-            self.fft_pass_blocked(output, workspace, stride, n);
+            self.fft_pass_blocked(data, twiddles, stride, n);
             stride *= 2;
         }
     }
 
     fn bit_reversal_blocked(&self, data: &mut [u64], n: usize) {
         const BLOCK_SIZE: usize = 64; // Tune for L1 cache
-        
+
         for block_start in (0..n).step_by(BLOCK_SIZE) {
             let block_end = (block_start + BLOCK_SIZE).min(n);
-            
+
             for i in block_start..block_end {
                 let j = self.reverse_bits(i, n.trailing_zeros());
                 if i < j && j < n {
@@ -107,23 +298,24 @@ impl StreamingProver {
         }
     }
 
-    fn fft_pass_blocked(&self, data: &mut [u64], workspace: &mut [u64], stride: usize, n: usize) {
-        // Implement cache-blocked FFT pass
-        // This is simplified - real implementation would do proper FFT
+    /// One decimation-in-time butterfly stage of the Cooley-Tukey NTT, using
+    /// the fast Goldilocks reduction instead of a generic `%`.
+    fn fft_pass_blocked(&self, data: &mut [u64], twiddles: &[u64], stride: usize, n: usize) {
         let half_stride = stride;
         let full_stride = stride * 2;
-        
+        // twiddles holds n/2 powers of the root; this stage only needs every
+        // (n/2)/half_stride'th entry.
+        let twiddle_step = (n / 2) / half_stride;
+
         for start in (0..n).step_by(full_stride) {
             for k in 0..half_stride {
                 let i = start + k;
-                let j = start + k + half_stride;
-                
-                if j < n {
-                    // Butterfly operation
-                    let t = data[j];
-                    data[j] = data[i].wrapping_sub(t);
-                    data[i] = data[i].wrapping_add(t);
-                }
+                let j = i + half_stride;
+
+                let w = twiddles[k * twiddle_step];
+                let t = gmul(w, data[j]);
+                data[j] = gsub(data[i], t);
+                data[i] = gadd(data[i], t);
             }
         }
     }
@@ -133,6 +325,141 @@ impl StreamingProver {
     }
 }
 
+/// One column of a `SpilledTable`: either a plain in-RAM vector (small
+/// tables) or a read-only view over a memory-mapped temp file (tables that
+/// exceeded `spill_threshold_bytes`).
+pub enum SpilledColumn {
+    InMemory(Vec<u64>),
+    Mapped { mmap: Mmap, _file: File },
+}
+
+impl SpilledColumn {
+    pub fn as_slice(&self) -> &[u64] {
+        match self {
+            SpilledColumn::InMemory(v) => v,
+            SpilledColumn::Mapped { mmap, .. } => {
+                debug_assert_eq!(mmap.len() % mem::size_of::<u64>(), 0);
+                let len = mmap.len() / mem::size_of::<u64>();
+                unsafe { std::slice::from_raw_parts(mmap.as_ptr() as *const u64, len) }
+            }
+        }
+    }
+}
+
+/// A table built by `build_table_spilled`. Keeps the backing temp
+/// directory (if any) alive for as long as the columns' mappings are in
+/// use; it's removed when this handle is dropped.
+pub struct SpilledTable {
+    columns: Vec<SpilledColumn>,
+    _tempdir: Option<TempDir>,
+}
+
+impl SpilledTable {
+    pub fn num_cols(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn column(&self, idx: usize) -> &[u64] {
+        self.columns[idx].as_slice()
+    }
+}
+
+// ============================================================================
+// Goldilocks field arithmetic: p = 2^64 - 2^32 + 1
+// ============================================================================
+
+const GOLDILOCKS_P: u64 = 0xFFFF_FFFF_0000_0001;
+/// `2^64 - GOLDILOCKS_P`, used by the fast reduction below.
+const EPSILON: u64 = 0xFFFF_FFFF;
+/// A generator of the full `p-1` order multiplicative group.
+const GENERATOR: u64 = 7;
+
+fn gadd(a: u64, b: u64) -> u64 {
+    let (sum, carry) = a.overflowing_add(b);
+    let sum = if carry { sum.wrapping_add(EPSILON) } else { sum };
+    if sum >= GOLDILOCKS_P {
+        sum - GOLDILOCKS_P
+    } else {
+        sum
+    }
+}
+
+fn gsub(a: u64, b: u64) -> u64 {
+    if a >= b {
+        a - b
+    } else {
+        GOLDILOCKS_P - (b - a)
+    }
+}
+
+/// Reduces a 128-bit product modulo `GOLDILOCKS_P` by folding the high
+/// 32/64-bit limbs instead of doing a generic 128-bit division.
+fn reduce128(x: u128) -> u64 {
+    let x_lo = x as u64;
+    let x_hi = (x >> 64) as u64;
+    let x_hi_hi = x_hi >> 32;
+    let x_hi_lo = x_hi & EPSILON;
+
+    let (t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+    let t0 = if borrow { t0.wrapping_sub(EPSILON) } else { t0 };
+
+    let t1 = x_hi_lo.wrapping_mul(EPSILON);
+    let (t2, carry) = t0.overflowing_add(t1);
+    let t2 = if carry { t2.wrapping_add(EPSILON) } else { t2 };
+
+    if t2 >= GOLDILOCKS_P {
+        t2 - GOLDILOCKS_P
+    } else {
+        t2
+    }
+}
+
+fn gmul(a: u64, b: u64) -> u64 {
+    reduce128((a as u128) * (b as u128))
+}
+
+fn gpow(base: u64, mut exp: u64) -> u64 {
+    let mut result = 1u64;
+    let mut b = base;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = gmul(result, b);
+        }
+        b = gmul(b, b);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Modular inverse via Fermat's little theorem (`a^(p-2) mod p`).
+fn ginv(a: u64) -> u64 {
+    gpow(a, GOLDILOCKS_P - 2)
+}
+
+/// The primitive `n`-th root of unity, derived from the field's generator
+/// `g` by `omega = g^((p-1)/n)`.
+fn primitive_root_of_unity(n: usize) -> u64 {
+    let log_n = n.trailing_zeros();
+    assert!(log_n <= 32, "Goldilocks has 2-adicity 32");
+    gpow(GENERATOR, (GOLDILOCKS_P - 1) >> log_n)
+}
+
+/// Fills `table` (length `n/2`) with consecutive powers of the `n`-th root
+/// of unity: `table[i] = omega^i`. Pass `inverse = true` to build the
+/// conjugate table (`omega^-1`) used by the inverse transform.
+fn fill_twiddle_table(table: &mut [u64], n: usize, inverse: bool) {
+    let mut root = primitive_root_of_unity(n);
+    if inverse {
+        root = ginv(root);
+    }
+
+    let mut cur = 1u64;
+    for slot in table.iter_mut() {
+        *slot = cur;
+        cur = gmul(cur, root);
+    }
+}
+
 /// Memory usage reporter
 pub fn report_memory_usage() {
     #[cfg(target_os = "linux")]
@@ -177,3 +504,56 @@ pub extern "C" fn streaming_build_table(
 pub extern "C" fn report_memory() {
     report_memory_usage();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `fft_chunked` (forward NTT) followed by `interpolate_streaming`
+    /// (inverse NTT, already `1/n`-scaled) must be the identity on the
+    /// original coefficient vector.
+    #[test]
+    fn ntt_then_intt_is_identity() {
+        let prover = StreamingProver::new();
+        let coeffs: Vec<u64> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let n = coeffs.len();
+
+        let mut evals = vec![0u64; n];
+        prover.fft_chunked(&coeffs, &mut evals);
+
+        let recovered = prover.interpolate_streaming(&evals, n);
+        assert_eq!(recovered.as_slice(), coeffs.as_slice());
+    }
+
+    /// Smallest nontrivial case: `n = 2`, worked out by hand. `omega = -1
+    /// mod p` is the only primitive square root of unity, so the forward
+    /// transform of `[a, b]` is `[a + b, a - b]`.
+    #[test]
+    fn ntt_matches_hand_computed_vector_for_n_2() {
+        let prover = StreamingProver::new();
+        let coeffs: Vec<u64> = vec![3, 5];
+
+        let mut evals = vec![0u64; 2];
+        prover.fft_chunked(&coeffs, &mut evals);
+
+        assert_eq!(evals, vec![gadd(3, 5), gsub(3, 5)]);
+
+        let recovered = prover.interpolate_streaming(&evals, 2);
+        assert_eq!(recovered.as_slice(), coeffs.as_slice());
+    }
+
+    /// The all-zero polynomial transforms (and inverse-transforms) to
+    /// itself at any domain size.
+    #[test]
+    fn ntt_then_intt_identity_on_zero_vector() {
+        let prover = StreamingProver::new();
+        let coeffs = vec![0u64; 16];
+
+        let mut evals = vec![0u64; 16];
+        prover.fft_chunked(&coeffs, &mut evals);
+        assert_eq!(evals, coeffs);
+
+        let recovered = prover.interpolate_streaming(&evals, 16);
+        assert_eq!(recovered.as_slice(), coeffs.as_slice());
+    }
+}
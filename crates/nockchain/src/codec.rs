@@ -0,0 +1,141 @@
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use nockapp::noun::slab::NounSlab;
+use tokio_util::codec::{Decoder, Encoder};
+
+/// Length-delimited `tokio_util::codec::Framed` codec for shipping a single
+/// jammed noun per frame: each frame is a 4-byte big-endian length prefix
+/// followed by that many bytes of jammed noun payload.
+///
+/// Not yet wired into [`crate::mining`]'s pool driver. That driver
+/// multiplexes two message kinds (`NewJob`/`SubmitSolution`) over one
+/// `TcpStream` by hand-rolling its own framing (`read_pool_frame`/
+/// `write_pool_frame`) with an extra tag byte ahead of the payload; this
+/// codec's frame has no room for that tag; `Self::Item` is a bare
+/// `NounSlab`, not `(PoolMessage, NounSlab)`. Swapping the pool driver over
+/// to a `Framed<_, CandidateCodec>` would mean extending this codec to
+/// encode/decode the tag too, not just pointing the driver at it.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CandidateCodec;
+
+impl Decoder for CandidateCodec {
+    type Item = NounSlab;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if src.len() < 4 {
+            return Ok(None);
+        }
+
+        let len = u32::from_be_bytes(src[..4].try_into().unwrap()) as usize;
+        if src.len() < 4 + len {
+            src.reserve(4 + len - src.len());
+            return Ok(None);
+        }
+
+        src.advance(4);
+        let payload = src.split_to(len);
+
+        let mut slab = NounSlab::new();
+        slab.cue_into(&payload)
+            .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "bad jammed noun"))?;
+        Ok(Some(slab))
+    }
+}
+
+impl Encoder<NounSlab> for CandidateCodec {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, item: NounSlab, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        let jammed = item.jam();
+        let payload = jammed.as_slice();
+
+        dst.reserve(4 + payload.len());
+        dst.put_u32(payload.len() as u32);
+        dst.put_slice(payload);
+        Ok(())
+    }
+}
+
+/// Encodes a candidate as a length-prefixed frame, independent of a
+/// `Framed` transport (e.g. to hand straight to a channel or a raw
+/// `write_all`).
+pub fn encode_candidate(candidate: &NounSlab) -> Bytes {
+    let mut dst = BytesMut::new();
+    // `CandidateCodec::encode` takes the slab by value; clone so this
+    // helper can stay `&NounSlab` like the rest of the mining code's noun
+    // accessors.
+    let mut cloned = NounSlab::new();
+    cloned.copy_into(unsafe { candidate.root() });
+    CandidateCodec
+        .encode(cloned, &mut dst)
+        .expect("encoding a candidate into a fresh buffer cannot fail");
+    dst.freeze()
+}
+
+/// Decodes one length-prefixed candidate frame out of `src`, consuming it
+/// on success. Returns `Ok(None)` if `src` doesn't yet hold a complete
+/// frame. A jammed-noun that fails to cue is a real error, not "wait for
+/// more data": by the time `cue_into` can fail, `CandidateCodec::decode`
+/// has already consumed the frame's length prefix and body out of `src`
+/// (`src.advance`/`src.split_to`), so a caller that treated that the same
+/// as "incomplete frame" would poll forever waiting for bytes that are
+/// already gone.
+pub fn decode_candidate(src: &mut BytesMut) -> std::io::Result<Option<NounSlab>> {
+    CandidateCodec.decode(src)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nockvm::noun::D;
+
+    fn candidate_slab(value: u64) -> NounSlab {
+        let mut slab = NounSlab::new();
+        slab.set_root(D(value));
+        slab
+    }
+
+    #[test]
+    fn round_trips_a_jammed_candidate() {
+        let candidate = candidate_slab(1337);
+        let frame = encode_candidate(&candidate);
+
+        let mut buf = BytesMut::from(&frame[..]);
+        let decoded = decode_candidate(&mut buf)
+            .expect("a well-formed frame must decode")
+            .expect("a complete frame must decode to Some");
+
+        assert_eq!(
+            unsafe { decoded.root() },
+            unsafe { candidate.root() }
+        );
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn reports_incomplete_frame_as_none_not_error() {
+        let candidate = candidate_slab(7);
+        let frame = encode_candidate(&candidate);
+
+        // All but the last byte of a complete frame: not an error, just not
+        // enough data yet.
+        let mut buf = BytesMut::from(&frame[..frame.len() - 1]);
+        let decoded = decode_candidate(&mut buf).expect("incomplete frame must not error");
+        assert!(decoded.is_none());
+    }
+
+    #[test]
+    fn propagates_a_corrupt_frame_as_an_error_instead_of_none() {
+        let len: u32 = 4;
+        let mut buf = BytesMut::new();
+        buf.put_u32(len);
+        buf.put_slice(&[0xFFu8; 4]); // not a valid jammed noun
+
+        let result = decode_candidate(&mut buf);
+        assert!(
+            result.is_err(),
+            "a corrupt frame's bytes are already consumed by the time cue_into fails; \
+             reporting Ok(None) here would make a polling caller wait forever"
+        );
+    }
+}
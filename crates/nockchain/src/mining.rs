@@ -13,7 +13,10 @@ use nockvm_macros::tas;
 use tempfile::tempdir;
 use tracing::{instrument, warn};
 use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 
 pub enum MiningWire {
     Mined,
@@ -78,6 +81,33 @@ pub fn create_mining_driver(
     mine: bool,
     init_complete_tx: Option<tokio::sync::oneshot::Sender<()>>,
 ) -> IODriverFn {
+    create_mining_driver_with_workers(mining_config, mine, 1, init_complete_tx)
+}
+
+/// Same as [`create_mining_driver`], but spawns `worker_count` concurrent
+/// `mining_attempt` tasks per candidate, each *intended* to cover a disjoint
+/// nonce sub-range of the same candidate (see `candidate_for_nonce_range`).
+/// The first worker to produce a `command` effect wins: it is poked as
+/// `MiningWire::Mined`, and every sibling worker for that candidate is
+/// immediately signalled to stop, the same way a new candidate pre-empts the
+/// previous one. `worker_count == 1` reduces to the original single-kernel
+/// behavior.
+///
+/// With `worker_count > 1`, this is not yet a real multi-core speedup: the
+/// `kernels::miner::KERNEL` jam in this tree doesn't understand the extended
+/// `[candidate nonce_start nonce_end]` shape, so every worker silently
+/// re-searches the identical full nonce range and they all finish around the
+/// same time - `worker_count` workers doing `worker_count` times the work for
+/// one attempt, not `worker_count` times the throughput. The partitioning
+/// only pays off once a kernel that understands that shape lands; until
+/// then, prefer `worker_count == 1`.
+pub fn create_mining_driver_with_workers(
+    mining_config: Option<Vec<MiningKeyConfig>>,
+    mine: bool,
+    worker_count: usize,
+    init_complete_tx: Option<tokio::sync::oneshot::Sender<()>>,
+) -> IODriverFn {
+    let worker_count = worker_count.max(1);
     Box::new(move |mut handle| {
         Box::pin(async move {
             let Some(configs) = mining_config else {
@@ -114,8 +144,13 @@ pub fn create_mining_driver(
                 return Ok(());
             }
             let mut next_attempt: Option<NounSlab> = None;
-            let mut current_attempt: tokio::task::JoinSet<()> = tokio::task::JoinSet::new();
-            let mut current_attempt_stop_tx: Option<tokio::sync::oneshot::Sender<()>> = None;
+            let mut current_attempt: tokio::task::JoinSet<Option<NounSlab>> =
+                tokio::task::JoinSet::new();
+            // Root of the cancellation tree for this driver. Each candidate gets a
+            // child token (cancelling it cancels every worker in its cohort at
+            // once); each worker gets a grandchild token of that.
+            let root_token = CancellationToken::new();
+            let mut current_candidate_token: Option<CancellationToken> = None;
 
             loop {
                 tokio::select! {
@@ -136,58 +171,296 @@ pub fn create_mining_driver(
                                 slab
                             };
 
-                            // If there's an active attempt, send it a stop signal.
-                            // This ensures that a new `mine` effect always tries to stop the current work.
-                            if let Some(tx) = current_attempt_stop_tx.take() { // Take the sender (making current_attempt_stop_tx None)
-                                let _ = tx.send(()); // Send stop signal. Ignore error if receiver already dropped.
+                            // A new candidate always pre-empts the whole cohort working
+                            // the previous one, before anything new is spawned.
+                            if let Some(token) = current_candidate_token.take() {
+                                token.cancel();
                             }
 
-                            // Create a new oneshot channel for the new attempt
-                            let (new_stop_tx, new_stop_rx) = tokio::sync::oneshot::channel();
-                            // Store the sender for this new attempt
-                            current_attempt_stop_tx = Some(new_stop_tx);
-
-                            // If a task is currently running OR `next_attempt` already holds a queued candidate,
-                            // then this new candidate becomes the next one to process.
-                            // Otherwise, spawn it immediately.
+                            // If a cohort is currently running OR `next_attempt` already holds a
+                            // queued candidate, then this new candidate becomes the next one to
+                            // process. Otherwise, spawn the cohort immediately.
                             if !current_attempt.is_empty() || next_attempt.is_some() {
                                 next_attempt = Some(candidate_slab);
                             } else {
-                                // No task is running and no next attempt is queued, so spawn immediately.
-                                let (cur_handle, attempt_handle) = handle.dup();
-                                handle = cur_handle;
-                                current_attempt.spawn(mining_attempt(
+                                current_candidate_token = Some(spawn_mining_cohort(
+                                    &mut handle,
+                                    &mut current_attempt,
+                                    &root_token,
                                     candidate_slab,
-                                    attempt_handle,
-                                    new_stop_rx, // <--- Pass the Receiver here
+                                    worker_count,
                                 ));
                             }
                         }
                     },
-                    // This branch fires when a spawned mining_attempt task completes
+                    // This branch fires whenever any worker in the cohort completes.
                     mining_attempt_res = current_attempt.join_next(), if !current_attempt.is_empty()  => {
-                        if let Some(Err(e)) = mining_attempt_res {
-                            warn!("Error during mining attempt: {e:?}");
+                        match mining_attempt_res {
+                            Some(Ok(Some(effect))) => {
+                                // A worker found a solution: cancel its siblings by
+                                // cancelling the whole candidate subtree.
+                                if let Some(token) = current_candidate_token.take() {
+                                    token.cancel();
+                                }
+                                handle
+                                    .poke(MiningWire::Mined.to_wire(), effect)
+                                    .await
+                                    .expect("Could not poke nockchain with mined PoW");
+                            }
+                            Some(Ok(None)) => {}
+                            Some(Err(e)) => warn!("Error during mining attempt: {e:?}"),
+                            None => {}
                         }
 
-                        // The task has completed, so its stop_tx is no longer relevant.
-                        current_attempt_stop_tx = None;
+                        // If the whole cohort has drained and there's a queued candidate,
+                        // spawn its cohort now.
+                        if current_attempt.is_empty() {
+                            if let Some(candidate_slab) = next_attempt.take() {
+                                current_candidate_token = Some(spawn_mining_cohort(
+                                    &mut handle,
+                                    &mut current_attempt,
+                                    &root_token,
+                                    candidate_slab,
+                                    worker_count,
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    })
+}
 
-                        // If there's a queued candidate, spawn it now
-                        if let Some(candidate_slab) = next_attempt.take() { // Use .take() to consume the value
-                            // Create a new oneshot channel for this new task
-                            let (new_stop_tx, new_stop_rx) = tokio::sync::oneshot::channel();
-                            // Store the sender for this new attempt
-                            current_attempt_stop_tx = Some(new_stop_tx);
+/// Spawns `worker_count` `mining_attempt` tasks for `candidate`, each tagged
+/// with a disjoint nonce sub-range (not yet honored by the kernel in this
+/// tree - see `candidate_for_nonce_range`), and returns the candidate's
+/// cancellation token: a child of `root_token` and the parent of every
+/// worker's own token, so cancelling it tears down the whole cohort.
+fn spawn_mining_cohort(
+    handle: &mut NockAppHandle,
+    current_attempt: &mut tokio::task::JoinSet<Option<NounSlab>>,
+    root_token: &CancellationToken,
+    candidate: NounSlab,
+    worker_count: usize,
+) -> CancellationToken {
+    let candidate_token = root_token.child_token();
+    let range_size = u64::MAX / worker_count as u64;
+
+    for worker_idx in 0..worker_count {
+        let nonce_start = range_size * worker_idx as u64;
+        let nonce_end = if worker_idx + 1 == worker_count {
+            u64::MAX
+        } else {
+            range_size * (worker_idx as u64 + 1)
+        };
+
+        let worker_candidate = candidate_for_nonce_range(&candidate, nonce_start, nonce_end);
+        let worker_token = candidate_token.child_token();
+
+        let (cur_handle, attempt_handle) = handle.dup();
+        *handle = cur_handle;
+        current_attempt.spawn(mining_attempt_worker(worker_candidate, attempt_handle, worker_token));
+    }
+
+    candidate_token
+}
+
+/// Tags a candidate with the `[nonce_start nonce_end]` sub-range this worker
+/// *would* search, if the kernel it's sent to understood the extended shape
+/// well enough to partition the nonce space instead of every worker
+/// redundantly retrying the same nonces.
+///
+/// As of this tree, it doesn't: `kernels::miner::KERNEL` only recognizes the
+/// plain `candidate` shape, ignores the trailing `nonce_start`/`nonce_end`
+/// cells, and searches the full space regardless - so every worker in a
+/// cohort currently does the same redundant work `worker_count` times over,
+/// with no speedup. This tags candidates now so that shipping the kernel-side
+/// change is a one-sided update (not a matching Rust change too), but it is
+/// not a functioning partition yet.
+fn candidate_for_nonce_range(candidate: &NounSlab, nonce_start: u64, nonce_end: u64) -> NounSlab {
+    let mut slab = NounSlab::new();
+    slab.copy_into(unsafe { candidate.root() });
+    let copied_candidate = unsafe { slab.root() };
+    let tagged = T(&mut slab, &[copied_candidate, D(nonce_start), D(nonce_end)]);
+    slab.set_root(tagged);
+    slab
+}
+
+/// Stratum-V2-style message tags exchanged with a remote pool coordinator.
+///
+/// Each message on the wire is a 4-byte big-endian length prefix (counting
+/// the tag byte and the payload) followed by a single tag byte and a jammed
+/// noun payload.
+#[repr(u8)]
+enum PoolMessage {
+    /// Coordinator -> miner: a new candidate to work on. Replaces whatever
+    /// attempt is currently in flight, exactly like a local `mine` effect.
+    NewJob = 0,
+    /// Miner -> coordinator: the jammed `command` effect found for the job
+    /// currently being worked. The frame carries only that noun; no share/m
+    /// accounting for this connection's key is attached.
+    SubmitSolution = 1,
+}
+
+impl TryFrom<u8> for PoolMessage {
+    type Error = NockAppError;
+
+    fn try_from(tag: u8) -> Result<Self, Self::Error> {
+        match tag {
+            0 => Ok(PoolMessage::NewJob),
+            1 => Ok(PoolMessage::SubmitSolution),
+            _ => Err(NockAppError::OtherError),
+        }
+    }
+}
+
+async fn read_pool_frame(stream: &mut TcpStream) -> Result<(PoolMessage, Vec<u8>), NockAppError> {
+    let mut len_buf = [0u8; 4];
+    stream
+        .read_exact(&mut len_buf)
+        .await
+        .map_err(|_| NockAppError::OtherError)?;
+    let len = u32::from_be_bytes(len_buf) as usize;
+
+    let mut tag_buf = [0u8; 1];
+    stream
+        .read_exact(&mut tag_buf)
+        .await
+        .map_err(|_| NockAppError::OtherError)?;
+    let tag = PoolMessage::try_from(tag_buf[0])?;
+
+    let mut payload = vec![0u8; len.saturating_sub(1)];
+    stream
+        .read_exact(&mut payload)
+        .await
+        .map_err(|_| NockAppError::OtherError)?;
+
+    Ok((tag, payload))
+}
+
+async fn write_pool_frame(
+    stream: &mut TcpStream,
+    tag: PoolMessage,
+    payload: &[u8],
+) -> Result<(), NockAppError> {
+    let len = (payload.len() + 1) as u32;
+    stream
+        .write_all(&len.to_be_bytes())
+        .await
+        .map_err(|_| NockAppError::OtherError)?;
+    stream
+        .write_all(&[tag as u8])
+        .await
+        .map_err(|_| NockAppError::OtherError)?;
+    stream
+        .write_all(payload)
+        .await
+        .map_err(|_| NockAppError::OtherError)?;
+    Ok(())
+}
+
+/// Networked counterpart to [`create_mining_driver`] that mines against a
+/// remote pool coordinator instead of driving a local `mine` effect loop.
+///
+/// The coordinator speaks a minimal Stratum-V2-flavored job-negotiation
+/// protocol: it pushes `NewJob` frames carrying a jammed candidate noun, and
+/// the driver pokes its kernel exactly the way `mining_attempt` does for a
+/// local `mine` effect, sending `SubmitSolution` back upstream on success
+/// (in addition to the usual in-process `MiningWire::Mined` poke, so the
+/// rest of the node still observes the share). A `NewJob` arriving while an
+/// attempt is in flight cancels it via the same stop-signal mechanism
+/// `create_mining_driver` uses, so the coordinator can always pre-empt stale
+/// work.
+pub fn create_pool_mining_driver(
+    coordinator_addr: String,
+    mining_config: MiningKeyConfig,
+    init_complete_tx: Option<tokio::sync::oneshot::Sender<()>>,
+) -> IODriverFn {
+    Box::new(move |mut handle| {
+        Box::pin(async move {
+            set_mining_key_advanced(&handle, vec![mining_config.clone()]).await?;
+            enable_mining(&handle, true).await?;
+
+            if let Some(tx) = init_complete_tx {
+                tx.send(()).map_err(|_| {
+                    warn!("Could not send driver initialization for pool mining driver.");
+                    NockAppError::OtherError
+                })?;
+            }
+
+            let mut stream = TcpStream::connect(&coordinator_addr)
+                .await
+                .map_err(|_| NockAppError::OtherError)?;
+
+            let mut current_attempt: tokio::task::JoinSet<Option<NounSlab>> =
+                tokio::task::JoinSet::new();
+            let root_token = CancellationToken::new();
+            let mut current_attempt_token: Option<CancellationToken> = None;
+
+            loop {
+                tokio::select! {
+                    frame_res = read_pool_frame(&mut stream) => {
+                        let (tag, payload) = match frame_res {
+                            Ok(frame) => frame,
+                            Err(e) => {
+                                warn!("Error reading frame from pool coordinator: {e:?}");
+                                return Err(e);
+                            }
+                        };
+
+                        if let PoolMessage::NewJob = tag {
+                            // A new job always pre-empts whatever attempt is in flight.
+                            if let Some(token) = current_attempt_token.take() {
+                                token.cancel();
+                            }
+
+                            let mut candidate_slab = NounSlab::new();
+                            candidate_slab
+                                .cue_into(payload.as_slice())
+                                .map_err(|_| NockAppError::OtherError)?;
+
+                            let job_token = root_token.child_token();
+                            current_attempt_token = Some(job_token.clone());
 
                             let (cur_handle, attempt_handle) = handle.dup();
                             handle = cur_handle;
-                            current_attempt.spawn(mining_attempt(
+                            current_attempt.spawn(mining_attempt_pool(
                                 candidate_slab,
                                 attempt_handle,
-                                new_stop_rx, // <--- Pass the Receiver here
+                                job_token,
                             ));
                         }
+                    },
+                    mining_attempt_res = current_attempt.join_next(), if !current_attempt.is_empty() => {
+                        // Don't touch `current_attempt_token` here: `NewJob`
+                        // cancels the old token and spawns the replacement
+                        // into the same `JoinSet` without waiting for the
+                        // cancelled task to drain, so this branch can fire
+                        // for a just-cancelled stale task while a live
+                        // attempt's token is the one currently stored.
+                        // Unconditionally clearing it would clobber that
+                        // live token, leaving the next `NewJob` unable to
+                        // cancel a still-running stale attempt. The only
+                        // thing that should ever replace this token is a
+                        // `NewJob` arriving (which takes and cancels it,
+                        // then stores the new one).
+
+                        match mining_attempt_res {
+                            Some(Ok(Some(solution))) => {
+                                let jammed = solution.jam();
+                                if let Err(e) = write_pool_frame(
+                                    &mut stream,
+                                    PoolMessage::SubmitSolution,
+                                    jammed.as_slice(),
+                                ).await {
+                                    warn!("Could not submit solution to pool coordinator: {e:?}");
+                                }
+                            }
+                            Some(Ok(None)) => {}
+                            Some(Err(e)) => warn!("Error during pool mining attempt: {e:?}"),
+                            None => {}
+                        }
                     }
                 }
             }
@@ -195,7 +468,105 @@ pub fn create_mining_driver(
     })
 }
 
-pub async fn mining_attempt(candidate: NounSlab, handle: NockAppHandle, mut stop_rx: tokio::sync::oneshot::Receiver<()>) -> () {
+/// Like [`mining_attempt`], it pokes the `command` effect into the node as
+/// `MiningWire::Mined`; it also returns that effect, so the pool driver can
+/// forward it upstream as a `SubmitSolution`.
+async fn mining_attempt_pool(
+    candidate: NounSlab,
+    handle: NockAppHandle,
+    cancel: CancellationToken,
+) -> Option<NounSlab> {
+    let snapshot_dir =
+        tokio::task::spawn_blocking(|| tempdir().expect("Failed to create temporary directory"))
+            .await
+            .expect("Failed to create temporary directory");
+    let hot_state = zkvm_jetpack::hot::produce_prover_hot_state();
+    let snapshot_path_buf = snapshot_dir.path().to_path_buf();
+    let jam_paths = JamPaths::new(snapshot_dir.path());
+
+    let kernel = Arc::new(Mutex::new(
+        Kernel::load_with_hot_state_huge(snapshot_path_buf, jam_paths, KERNEL, &hot_state, false)
+            .await
+            .expect("Could not load mining kernel"),
+    ));
+
+    tokio::select! {
+        effects_slab_res = async {
+            let k_guard = kernel.lock().await;
+            k_guard.poke(MiningWire::Candidate.to_wire(), candidate).await
+        } => {
+            let effects_slab = effects_slab_res.expect("Could not poke mining kernel with candidate");
+            for effect in effects_slab.to_vec() {
+                let Ok(effect_cell) = (unsafe { effect.root().as_cell() }) else {
+                    drop(effect);
+                    continue;
+                };
+                if effect_cell.head().eq_bytes("command") {
+                    handle
+                        .poke(MiningWire::Mined.to_wire(), effect.clone())
+                        .await
+                        .expect("Could not poke nockchain with mined PoW");
+                    return Some(effect);
+                }
+            }
+            None
+        },
+        _ = cancel.cancelled() => {
+            let mut k_guard = kernel.lock().await;
+            let _ = k_guard.stop().await;
+            None
+        }
+    }
+}
+
+/// Like [`mining_attempt`], but returns the `command` effect (if any) instead
+/// of poking it, so a cohort of sibling workers can race for a candidate and
+/// let the driver poke only the winner.
+async fn mining_attempt_worker(
+    candidate: NounSlab,
+    _handle: NockAppHandle,
+    cancel: CancellationToken,
+) -> Option<NounSlab> {
+    let snapshot_dir =
+        tokio::task::spawn_blocking(|| tempdir().expect("Failed to create temporary directory"))
+            .await
+            .expect("Failed to create temporary directory");
+    let hot_state = zkvm_jetpack::hot::produce_prover_hot_state();
+    let snapshot_path_buf = snapshot_dir.path().to_path_buf();
+    let jam_paths = JamPaths::new(snapshot_dir.path());
+
+    let kernel = Arc::new(Mutex::new(
+        Kernel::load_with_hot_state_huge(snapshot_path_buf, jam_paths, KERNEL, &hot_state, false)
+            .await
+            .expect("Could not load mining kernel"),
+    ));
+
+    tokio::select! {
+        effects_slab_res = async {
+            let k_guard = kernel.lock().await;
+            k_guard.poke(MiningWire::Candidate.to_wire(), candidate).await
+        } => {
+            let effects_slab = effects_slab_res.expect("Could not poke mining kernel with candidate");
+            for effect in effects_slab.to_vec() {
+                let Ok(effect_cell) = (unsafe { effect.root().as_cell() }) else {
+                    drop(effect);
+                    continue;
+                };
+                if effect_cell.head().eq_bytes("command") {
+                    return Some(effect);
+                }
+            }
+            None
+        },
+        _ = cancel.cancelled() => {
+            let mut k_guard = kernel.lock().await;
+            let _ = k_guard.stop().await;
+            None
+        }
+    }
+}
+
+pub async fn mining_attempt(candidate: NounSlab, handle: NockAppHandle, cancel: CancellationToken) -> () {
     let snapshot_dir =
         tokio::task::spawn_blocking(|| tempdir().expect("Failed to create temporary directory"))
             .await
@@ -235,8 +606,8 @@ pub async fn mining_attempt(candidate: NounSlab, handle: NockAppHandle, mut stop
                 }
             }
         },
-        // Branch 2: A stop signal is received
-        _ = &mut stop_rx => {
+        // Branch 2: The cancellation token is cancelled
+        _ = cancel.cancelled() => {
             // Signal received. Call the async kernel.stop() method and await it.
             // Acquire the lock here as well to ensure exclusive access for stop().
             let mut k_guard = kernel.lock().await;
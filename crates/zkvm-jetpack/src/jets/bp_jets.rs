@@ -5,8 +5,10 @@ use nockvm::jets::Result;
 use nockvm::jets::JetErr;
 use nockvm::noun::{Atom, IndirectAtom, Noun, D, T};
 
-use tracing::info;
+use std::collections::VecDeque;
+use std::sync::Mutex;
 
+use crate::form::mary::*;
 use crate::form::math::bpoly::*;
 use crate::form::poly::*;
 use crate::hand::handle::*;
@@ -14,6 +16,55 @@ use crate::hand::structs::HoonList;
 use crate::jets::utils::jet_err;
 use crate::noun::noun_ext::{AtomExt, NounExt};
 
+// Pool of reusable `Belt` scratch buffers for `bpmul_ntt`'s padded operands
+// and evaluation vectors, so the NTT fast path added for `bpmul_jet`
+// doesn't hit the global heap allocator on every call - the same pooling
+// idea `hoonc::memory_pool::PooledVec` uses for `u64` buffers, sized for
+// this specific hot loop rather than generalized across size classes.
+lazy_static::lazy_static! {
+    static ref BELT_SCRATCH_POOL: Mutex<VecDeque<Vec<Belt>>> = Mutex::new(VecDeque::new());
+}
+
+struct BeltScratch {
+    data: Vec<Belt>,
+}
+
+impl BeltScratch {
+    fn new(size: usize) -> Self {
+        let mut data = BELT_SCRATCH_POOL
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or_default();
+        data.clear();
+        data.resize(size, Belt(0));
+        BeltScratch { data }
+    }
+}
+
+impl std::ops::Deref for BeltScratch {
+    type Target = [Belt];
+    fn deref(&self) -> &[Belt] {
+        &self.data
+    }
+}
+
+impl std::ops::DerefMut for BeltScratch {
+    fn deref_mut(&mut self) -> &mut [Belt] {
+        &mut self.data
+    }
+}
+
+impl Drop for BeltScratch {
+    fn drop(&mut self) {
+        let data = std::mem::take(&mut self.data);
+        let mut pool = BELT_SCRATCH_POOL.lock().unwrap();
+        if pool.len() < 32 {
+            pool.push_back(data);
+        }
+    }
+}
+
 pub fn bpoly_to_list_jet(context: &mut Context, subject: Noun) -> Result {
     let sam = slot(subject, 6)?;
     bpoly_to_list(context, sam)
@@ -113,6 +164,11 @@ pub fn bpscal_jet(context: &mut Context, subject: Noun) -> Result {
     Ok(res_cell)
 }
 
+// Below this result length, the NTT path's setup (padding to a power of
+// two, two forward transforms, an inverse transform) costs more than the
+// schoolbook convolution it would replace.
+const BPMUL_NTT_THRESHOLD: usize = 64;
+
 pub fn bpmul_jet(context: &mut Context, subject: Noun) -> Result {
     let sam = slot(subject, 6)?;
     let bp = slot(sam, 2)?;
@@ -122,7 +178,8 @@ pub fn bpmul_jet(context: &mut Context, subject: Noun) -> Result {
         return jet_err();
     };
 
-    let res_len = if bp_poly.is_zero() | bq_poly.is_zero() {
+    let is_zero = bp_poly.is_zero() | bq_poly.is_zero();
+    let res_len = if is_zero {
         1
     } else {
         bp_poly.len() + bq_poly.len() - 1
@@ -131,12 +188,50 @@ pub fn bpmul_jet(context: &mut Context, subject: Noun) -> Result {
     let (res_atom, res_poly): (IndirectAtom, &mut [Belt]) =
         new_handle_mut_slice(&mut context.stack, Some(res_len));
 
-    bpmul(bp_poly.0, bq_poly.0, res_poly);
+    let mut done = false;
+    if !is_zero && res_len > BPMUL_NTT_THRESHOLD {
+        if let Some(fast_result) = bpmul_ntt(bp_poly.0, bq_poly.0, res_len) {
+            res_poly.copy_from_slice(&fast_result);
+            done = true;
+        }
+    }
+    if !done {
+        bpmul(bp_poly.0, bq_poly.0, res_poly);
+    }
+
     let res_cell = finalize_poly(&mut context.stack, Some(res_len), res_atom);
 
     Ok(res_cell)
 }
 
+// NTT-convolution path for `bpmul`: zero-pad both operands to the same
+// power-of-two length `n >= res_len`, evaluate each at the order-`n` roots
+// of unity (`bp_ntt`), take the pointwise Hadamard product, and interpolate
+// back (`bp_ifft`). `None` if `n` has no order-`n` root available (the
+// Goldilocks field's multiplicative group has a 2^32-order subgroup, so
+// this only happens for implausibly large `n`), letting the caller fall
+// back to the schoolbook path.
+fn bpmul_ntt(bp: &[Belt], bq: &[Belt], res_len: usize) -> Option<Vec<Belt>> {
+    let n = res_len.next_power_of_two();
+    let root = Belt(n as u64).ordered_root().ok()?;
+
+    let mut bp_padded = BeltScratch::new(n);
+    bp_padded[..bp.len()].copy_from_slice(bp);
+    let mut bq_padded = BeltScratch::new(n);
+    bq_padded[..bq.len()].copy_from_slice(bq);
+
+    let bp_evals = bp_ntt(&bp_padded, &root);
+    let bq_evals = bp_ntt(&bq_padded, &root);
+
+    let mut prod_evals = BeltScratch::new(n);
+    bp_hadamard(&bp_evals, &bq_evals, &mut prod_evals);
+
+    let inv_root = root.inv();
+    let prod_coeffs = bp_ifft(&prod_evals, &inv_root).ok()?;
+
+    Some(prod_coeffs[..res_len].to_vec())
+}
+
 pub fn bp_hadamard_jet(context: &mut Context, subject: Noun) -> Result {
     let sam = slot(subject, 6)?;
     let bp = slot(sam, 2)?;
@@ -166,7 +261,13 @@ pub fn bp_ntt_jet(context: &mut Context, subject: Noun) -> Result {
     };
     let root_64 = root_atom.as_u64()?;
     let returned_bpoly = bp_ntt(bp_poly.0, &Belt(root_64));
-    // TODO: preallocate and pass res buffer into bp_ntt?
+    // TODO: `bp_ntt` itself still returns a freshly allocated `Vec` - giving
+    // it a caller-provided scratch/output buffer instead would mean adding a
+    // variant in `form::math::bpoly`, which isn't part of this crate's
+    // snapshot (only the `bp_jets.rs` call sites are). `bpmul_ntt` above pools
+    // its own scratch via `BeltScratch` for the allocations that *do* live in
+    // this file; the `bp_ntt`/`bp_fft`/`bp_ifft`/`bp_coseword` library calls
+    // themselves are a follow-up for whoever owns that module.
     let (res_atom, res_poly): (IndirectAtom, &mut [Belt]) =
         new_handle_mut_slice(&mut context.stack, Some(returned_bpoly.len() as usize));
     res_poly.copy_from_slice(&returned_bpoly[..]);
@@ -259,129 +360,119 @@ pub fn bp_coseword_jet(context: &mut Context, subject: Noun) -> Result {
     Ok(res_cell)
 }
 
-pub fn turn_coseword_jet(context: &mut nockvm::interpreter::Context, subject: Noun) -> Result {
-    let cell_subject = subject.as_cell()?;
-    let polys_noun = cell_subject.head();
-    let rest_of_subject_noun = cell_subject.tail();
-    let rest_of_subject_cell = rest_of_subject_noun.as_cell()?;
-
-    let offset_noun = rest_of_subject_cell.head();
-    let order_noun = rest_of_subject_cell.tail();
-
-    info!("&polys_noun = {:?}", polys_noun);
-    info!("&offset_noun = {:?}", offset_noun);
-    info!("&order_noun = {:?}", order_noun);
-
-    info!("offset_noun = {:?}", offset_noun);
-    // Ensure offset_noun is a cell, as logs confirm it is
-    let offset_cell = offset_noun.as_cell()?;
-    info!("offset_cell.head() = {:?}", offset_cell.head());
-    info!("offset_cell.tail() = {:?}", offset_cell.tail());
-
-    let head = offset_cell.head();
-    if head.is_cell() {
-        let head_cell = head.as_cell()?;
-        info!("offset_cell.head().head() = {:?}", head_cell.head());
-        info!("offset_cell.head().tail() = {:?}", head_cell.tail());
-
-        let head_head = head_cell.head();
-        if head_head.is_cell() {
-            let head_head_cell = head_head.as_cell()?;
-            info!("offset_cell.head().head().head() = {:?}", head_head_cell.head());
-            info!("offset_cell.head().head().tail() = {:?}", head_head_cell.tail());
-        } else {
-            info!("offset_cell.head().head() is atom: {:?}", head_head.as_atom());
-        }
+/// The plural form of `bp_coseword_jet`: maps `bp_coseword` across a
+/// `HoonList` of bpolys under one shared `(offset, order)` pair, producing
+/// a `mary` whose rows are each input polynomial's coset evaluation. The
+/// order-`order` root is computed once via `ordered_root()` and reused for
+/// every row, instead of every row re-deriving it the way a naive per-poly
+/// loop over `bp_coseword_jet` would.
+pub fn turn_coseword_jet(context: &mut Context, subject: Noun) -> Result {
+    let sam = slot(subject, 6)?;
+    let polys = slot(sam, 2)?;
+    let offset = slot(sam, 6)?;
+    let order = slot(sam, 7)?;
 
-        let head_tail = head_cell.tail();
-        if head_tail.is_cell() {
-            let head_tail_cell = head_tail.as_cell()?;
-            info!("offset_cell.head().tail().head() = {:?}", head_tail_cell.head());
-            info!("offset_cell.head().tail().tail() = {:?}", head_tail_cell.tail());
-        } else {
-            info!("offset_cell.head().tail() is atom: {:?}", head_tail.as_atom());
-        }
-    } else {
-        info!("offset_cell.head() is atom: {:?}", head.as_atom());
+    let (Ok(poly_list), Ok(offset_belt), Ok(order_atom)) =
+        (HoonList::try_from(polys), offset.as_belt(), order.as_atom())
+    else {
+        return jet_err();
+    };
+    let order_32: u32 = order_atom.as_u32()?;
+    let step = order_32 as usize;
+    let root = Belt(order_32 as u64).ordered_root()?;
+
+    let poly_nouns: Vec<Noun> = poly_list.into_iter().collect();
+    let len = poly_nouns.len();
+
+    let (res, mut res_poly): (IndirectAtom, MarySliceMut) =
+        new_handle_mut_mary(&mut context.stack, step, len);
+
+    for (i, poly_noun) in poly_nouns.into_iter().enumerate() {
+        let Ok(p_poly) = BPolySlice::try_from(poly_noun) else {
+            return jet_err();
+        };
+        let returned_bpoly = bp_coseword(p_poly.0, &offset_belt, order_32, &root);
+        res_poly.dat[(i * step)..(i + 1) * step].copy_from_slice(&returned_bpoly);
     }
 
-    let tail = offset_cell.tail();
-    if tail.is_cell() {
-        let tail_cell = tail.as_cell()?;
-        info!("offset_cell.tail().head() = {:?}", tail_cell.head());
-        info!("offset_cell.tail().tail() = {:?}", tail_cell.tail());
-    } else {
-        info!("offset_cell.tail() is atom: {:?}", tail.as_atom());
+    let res_cell = finalize_mary(&mut context.stack, step, len, res);
+
+    Ok(res_cell)
+}
+
+/// Collapses the transpose-then-map-then-transpose dance a column-major
+/// coset low-degree-extension otherwise takes several Hoon round-trips
+/// (`mary_transpose_jet`, a per-column `bp_coseword_jet`, `mary_weld_jet`)
+/// into one jet: `ma`'s rows are evaluation points and columns are trace
+/// polynomials in coefficient form, so it's transposed to column-major
+/// (same `mary_transpose` call `transpose_bpolys_jet` makes), every column
+/// is coset-evaluated at the shared blown-up order and root, and the
+/// result is transposed back so rows are evaluation points again.
+pub fn mary_coset_lde_jet(context: &mut Context, subject: Noun) -> Result {
+    let sam = slot(subject, 6)?;
+    let ma = slot(sam, 2)?;
+    let blowup = slot(sam, 6)?;
+    let offset = slot(sam, 7)?;
+
+    let (Ok(mary), Ok(blowup_atom), Ok(offset_belt)) =
+        (MarySlice::try_from(ma), blowup.as_atom(), offset.as_belt())
+    else {
+        return jet_err();
+    };
+    let blowup_32: u32 = blowup_atom.as_u32()?;
+
+    let trace_len = mary.len as usize;
+    let width = mary.step as usize;
+    if trace_len == 0 || width == 0 {
+        return jet_err();
     }
 
-    info!("order_noun = {:?}", order_noun);
-    // Ensure order_noun is a cell, as logs confirm it is
-    let order_cell = order_noun.as_cell()?;
-    info!("order_cell.head() = {:?}", order_cell.head());
-    info!("order_cell.tail() = {:?}", order_cell.tail());
-
-    let head = order_cell.head();
-    if head.is_cell() {
-        let head_cell = head.as_cell()?;
-        info!("order_cell.head().head() = {:?}", head_cell.head());
-        info!("order_cell.head().tail() = {:?}", head_cell.tail());
-
-        let head_head = head_cell.head();
-        if head_head.is_cell() {
-            let head_head_cell = head_head.as_cell()?;
-            info!("order_cell.head().head().head() = {:?}", head_head_cell.head());
-            info!("order_cell.head().head().tail() = {:?}", head_head_cell.tail());
-
-            let head_head_head = head_head_cell.head();
-            if head_head_head.is_cell() {
-                let head_head_head_cell = head_head_head.as_cell()?;
-                info!("order_cell.head().head().head().head() = {:?}", head_head_head_cell.head());
-                info!("order_cell.head().head().head().tail() = {:?}", head_head_head_cell.tail());
-            } else {
-                info!("order_cell.head().head().head() is atom: {:?}", head_head_head.as_atom());
-            }
-
-            let head_head_tail = head_head_cell.tail();
-            if head_head_tail.is_cell() {
-                let head_head_tail_cell = head_head_tail.as_cell()?;
-                info!("order_cell.head().head().tail().head() = {:?}", head_head_tail_cell.head());
-                info!("order_cell.head().head().tail().tail() = {:?}", head_head_tail_cell.tail());
-            } else {
-                info!("order_cell.head().head().tail() is atom: {:?}", head_head_tail.as_atom());
-            }
-        } else {
-            info!("order_cell.head().head() is atom: {:?}", head_head.as_atom());
-        }
+    let Some(order_32) = (trace_len as u32).checked_mul(blowup_32) else {
+        return jet_err();
+    };
+    let order = order_32 as usize;
+    let root = Belt(order_32 as u64).ordered_root()?;
 
-        let tail = head_cell.tail();
-        if tail.is_cell() {
-            let tail_cell = tail.as_cell()?;
-            info!("order_cell.head().tail().head() = {:?}", tail_cell.head());
-            info!("order_cell.head().tail().tail() = {:?}", tail_cell.tail());
-        } else {
-            info!("order_cell.head().tail() is atom: {:?}", tail.as_atom());
-        }
-    } else {
-        info!("order_cell.head() is atom: {:?}", head.as_atom());
+    let (_cols_atom, mut cols): (IndirectAtom, MarySliceMut) =
+        new_handle_mut_mary(&mut context.stack, trace_len, width);
+    mary_transpose(mary, 1, &mut cols);
+
+    let (evaled_atom, mut evaled): (IndirectAtom, MarySliceMut) =
+        new_handle_mut_mary(&mut context.stack, order, width);
+
+    // One zero-padded, coset-shifted scratch buffer, reused for every
+    // column instead of every column's transform paying for its own
+    // allocation - the same pooling idea `bpmul_ntt` uses for its padded
+    // operands. Only the first `trace_len` entries ever change between
+    // columns; the zero-padded tail written by `BeltScratch::new` is never
+    // touched again, so it stays zero for every iteration.
+    let mut padded = BeltScratch::new(order);
+    for i in 0..width {
+        let col = &cols.dat[(i * trace_len)..(i + 1) * trace_len];
+        bp_shift(col, &offset_belt, &mut padded[..trace_len]);
+        let evals = bp_ntt(&padded, &root);
+        evaled.dat[(i * order)..(i + 1) * order].copy_from_slice(&evals);
     }
 
-    let tail = order_cell.tail();
-    if tail.is_cell() {
-        let tail_cell = tail.as_cell()?;
-        info!("order_cell.tail().head() = {:?}", tail_cell.head());
-        info!("order_cell.tail().tail() = {:?}", tail_cell.tail());
-    } else {
-        info!("order_cell.tail() is atom: {:?}", tail.as_atom());
+    let evaled_cell = finalize_mary(&mut context.stack, order, width, evaled_atom);
+    let Ok(evaled_mary) = MarySlice::try_from(evaled_cell) else {
+        return jet_err();
+    };
+
+    // Same `step` invariant `mary_weld_jet` checks before combining two
+    // marys: the transpose back out only makes sense if every column came
+    // back at the one shared `order` we evaluated it at.
+    if evaled_mary.step as usize != order {
+        return jet_err();
     }
 
-    // === CORRECTED LINES START HERE ===
-    // Each call to .tail() returns a Noun. To call .head() on it, it must be
-    // explicitly converted back to a Cell using .as_cell()?.
-    let offset_atom = offset_cell.tail().as_cell()?.head().as_atom()?; // Example: Extracts '7' from your logs
-    let order_atom = order_cell.tail().as_cell()?.head().as_atom()?;   // Example: Extracts '3' from your logs
-    // === CORRECTED LINES END HERE ===
+    let (res, mut res_poly): (IndirectAtom, MarySliceMut) =
+        new_handle_mut_mary(&mut context.stack, width, order);
+    mary_transpose(evaled_mary, 1, &mut res_poly);
 
-    Ok(Noun::from_atom(Atom::new(&mut context.stack, 0)))
+    let res_cell = finalize_mary(&mut context.stack, width, order, res);
+
+    Ok(res_cell)
 }
 
 pub fn init_bpoly_jet(context: &mut Context, subject: Noun) -> Result {
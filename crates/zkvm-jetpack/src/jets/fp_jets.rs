@@ -221,133 +221,262 @@ pub fn fp_ifft_jet(context: &mut Context, subject: Noun) -> Result {
     Ok(res_cell)
 }
 
-// FFT using Number Theoretic Transform (NTT) algorithm - matches Hoon's fp-ntt
-fn fp_fft_poly(p: &[Felt], res: &mut [Felt]) {
-    let n = p.len();
-    
-    // Must be power of 2
-    assert!(n & (n - 1) == 0, "FFT requires power-of-2 length");
-    
-    // Base case: if length is 1, just copy
-    if n == 1 {
-        res[0] = p[0];
+// Precomputed twiddle factors for an iterative in-place NTT of a fixed
+// size: row `s` holds `2^s` consecutive powers of the `2^(s+1)`-th root of
+// unity, built by repeated squaring of the base root (same scheme as
+// plonky2's `fft_root_table`) so `fft_in_place` never calls `fpow_` on a
+// per-element basis. Reusable across every transform of that size and
+// direction — the multiply path below builds one table for its two forward
+// transforms and a second, inverse-root table for its inverse transform.
+struct FftRootTable {
+    rows: Vec<Vec<Felt>>,
+}
+
+impl FftRootTable {
+    fn new(log_n: usize, root: Felt) -> Self {
+        let mut rows_desc = Vec::with_capacity(log_n);
+        let mut stage_root = root;
+
+        for s in (0..log_n).rev() {
+            let half_m = 1usize << s;
+            let mut row = Vec::with_capacity(half_m);
+            let mut power = Felt::one();
+            for _ in 0..half_m {
+                row.push(power);
+                let mut next = Felt::zero();
+                fmul(&power, &stage_root, &mut next);
+                power = next;
+            }
+            rows_desc.push(row);
+
+            let mut squared = Felt::zero();
+            fmul(&stage_root, &stage_root, &mut squared);
+            stage_root = squared;
+        }
+
+        rows_desc.reverse();
+        FftRootTable { rows: rows_desc }
+    }
+}
+
+// `bit_reverse(i, bits)`: reverses the low `bits` bits of `i`, used by the
+// bit-reversal permutation every iterative FFT starts with.
+fn bit_reverse(mut i: usize, bits: usize) -> usize {
+    let mut r = 0usize;
+    for _ in 0..bits {
+        r = (r << 1) | (i & 1);
+        i >>= 1;
+    }
+    r
+}
+
+// Iterative, in-place decimation-in-time NTT (Cooley-Tukey): a bit-reversal
+// permutation followed by `log_n` butterfly stages, each reading its
+// twiddle factors straight out of `table` instead of recomputing them.
+fn fft_in_place(a: &mut [Felt], table: &FftRootTable) {
+    let n = a.len();
+    if n <= 1 {
         return;
     }
-    
-    let half = n / 2;
     let log_n = n.trailing_zeros() as usize;
-    let root = get_root_of_unity(log_n);
-    
-    // Separate even and odd indices
-    let mut evens = vec![Felt::zero(); half];
-    let mut odds = vec![Felt::zero(); half];
-    
+
     for i in 0..n {
-        if i % 2 == 0 {
-            evens[i / 2] = p[i];
-        } else {
-            odds[i / 2] = p[i];
+        let r = bit_reverse(i, log_n);
+        if r > i {
+            a.swap(i, r);
         }
     }
-    
-    // Recursively compute FFT of evens and odds
-    let mut evens_fft = vec![Felt::zero(); half];
-    let mut odds_fft = vec![Felt::zero(); half];
-    
-    // Square the root for recursive calls
-    let mut root_squared = Felt::zero();
-    fmul(&root, &root, &mut root_squared);
-    
-    // Recursive FFT on halves
-    fp_fft_recursive(&evens, &mut evens_fft, &root_squared);
-    fp_fft_recursive(&odds, &mut odds_fft, &root_squared);
-    
-    // Combine results: res[i] = evens_fft[i % half] + root^i * odds_fft[i % half]
-    for i in 0..n {
-        let mut root_power = fpow_(&root, i as u64);
-        let mut term = Felt::zero();
-        fmul(&root_power, &odds_fft[i % half], &mut term);
-        fadd(&evens_fft[i % half], &term, &mut res[i]);
+
+    fft_butterflies(a, table, 0, log_n);
+}
+
+// The butterfly-stage half of `fft_in_place`, factored out so
+// `fp_lde_poly` can start partway up the stage ladder (`start_stage > 0`)
+// once it has already accounted for the lower stages some other way.
+fn fft_butterflies(a: &mut [Felt], table: &FftRootTable, start_stage: usize, log_n: usize) {
+    for s in start_stage..log_n {
+        let m = 1usize << (s + 1);
+        let half_m = m / 2;
+        let row = &table.rows[s];
+
+        let mut k = 0;
+        while k < a.len() {
+            for j in 0..half_m {
+                let mut t = Felt::zero();
+                fmul(&row[j], &a[k + j + half_m], &mut t);
+
+                let upper = a[k + j];
+                let mut sum = Felt::zero();
+                fadd(&upper, &t, &mut sum);
+                let mut diff = Felt::zero();
+                fsub(&upper, &t, &mut diff);
+
+                a[k + j] = sum;
+                a[k + j + half_m] = diff;
+            }
+            k += m;
+        }
     }
 }
 
-// Recursive helper for FFT
-fn fp_fft_recursive(p: &[Felt], res: &mut [Felt], root: &Felt) {
+// FFT using Number Theoretic Transform (NTT) algorithm - matches Hoon's fp-ntt
+fn fp_fft_poly(p: &[Felt], res: &mut [Felt]) {
     let n = p.len();
-    
-    if n == 1 {
-        res[0] = p[0];
+
+    // Must be power of 2
+    assert!(n & (n - 1) == 0, "FFT requires power-of-2 length");
+
+    res.copy_from_slice(p);
+    if n <= 1 {
         return;
     }
-    
-    let half = n / 2;
-    
-    // Separate even and odd indices
-    let mut evens = vec![Felt::zero(); half];
-    let mut odds = vec![Felt::zero(); half];
-    
-    for i in 0..n {
-        if i % 2 == 0 {
-            evens[i / 2] = p[i];
-        } else {
-            odds[i / 2] = p[i];
-        }
-    }
-    
-    // Square the root for recursive calls
-    let mut root_squared = Felt::zero();
-    fmul(root, root, &mut root_squared);
-    
-    // Recursive FFT on halves
-    let mut evens_fft = vec![Felt::zero(); half];
-    let mut odds_fft = vec![Felt::zero(); half];
-    
-    fp_fft_recursive(&evens, &mut evens_fft, &root_squared);
-    fp_fft_recursive(&odds, &mut odds_fft, &root_squared);
-    
-    // Combine results
-    for i in 0..n {
-        let mut root_power = fpow_(root, i as u64);
-        let mut term = Felt::zero();
-        fmul(&root_power, &odds_fft[i % half], &mut term);
-        fadd(&evens_fft[i % half], &term, &mut res[i]);
-    }
+
+    let log_n = n.trailing_zeros() as usize;
+    let root = get_root_of_unity(log_n);
+    let table = FftRootTable::new(log_n, root);
+    fft_in_place(res, &table);
 }
 
 // Inverse FFT implementation - matches Hoon's fp-ifft
 fn fp_ifft_poly(p: &[Felt], res: &mut [Felt]) {
     let n = p.len();
-    
+
     // Must be power of 2
     assert!(n & (n - 1) == 0, "IFFT requires power-of-2 length");
-    
-    // Get root of unity and invert it
-    let log_n = n.trailing_zeros() as usize;
-    let root = get_root_of_unity(log_n);
-    let mut inv_root = Felt::zero();
-    finv(&root, &mut inv_root);
-    
-    // Run FFT with inverse root
-    fp_fft_recursive(p, res, &inv_root);
-    
+
+    res.copy_from_slice(p);
+    if n > 1 {
+        let log_n = n.trailing_zeros() as usize;
+        let root = get_root_of_unity(log_n);
+        let mut inv_root = Felt::zero();
+        finv(&root, &mut inv_root);
+        let table = FftRootTable::new(log_n, inv_root);
+        fft_in_place(res, &table);
+    }
+
     // Scale by 1/n
     let n_felt = Felt::from([Belt(n as u64), Belt(0), Belt(0)]);
     let mut inv_n = Felt::zero();
     finv(&n_felt, &mut inv_n);
-    
+
     for i in 0..n {
         let temp = res[i];
         fmul(&temp, &inv_n, &mut res[i]);
     }
 }
 
-// interpolate_jet: Lagrange interpolation
+// fp_lde_jet: low-degree extension - evaluate a length-`n` coefficient
+// polynomial over a size-`N = n*blowup` (optionally coset-shifted)
+// subgroup, via a single size-`N` NTT of the zero-padded input
+pub fn fp_lde_jet(context: &mut Context, subject: Noun) -> Result {
+    let sam = slot(subject, 6)?;
+    let p = slot(sam, 2)?;
+    let blowup = slot(sam, 6)?;
+    let shift = slot(sam, 7)?;
+
+    let (Ok(p_poly), Ok(blowup_atom), Ok(shift_felt)) =
+        (FPolySlice::try_from(p), blowup.as_atom(), shift.as_felt())
+    else {
+        return jet_err();
+    };
+    let Ok(blowup_u64) = blowup_atom.as_u64() else {
+        return jet_err();
+    };
+
+    let n = p_poly.len();
+    if blowup_u64 == 0 || n == 0 {
+        return jet_err();
+    }
+    let Some(big_n) = (blowup_u64 as usize).checked_mul(n) else {
+        return jet_err();
+    };
+
+    let Some(evals) = fp_lde_poly(p_poly.data(), big_n, &shift_felt) else {
+        return jet_err();
+    };
+
+    let (res, res_poly): (IndirectAtom, &mut [Felt]) =
+        new_handle_mut_slice(&mut context.stack, Some(evals.len()));
+    res_poly.copy_from_slice(&evals);
+    let res_cell = finalize_poly(&mut context.stack, Some(res_poly.len()), res);
+
+    Ok(res_cell)
+}
+
+// Core of `fp_lde_jet`. `n = coeffs.len()` and `big_n` must both be powers
+// of two with `big_n = n * blowup`; `None` on any other shape, including a
+// `big_n` too large for the precomputed root table. When `shift` isn't the
+// identity, coefficient `i` is pre-scaled by `shift^i`, which evaluates
+// over the coset `shift * H` instead of the subgroup `H` itself.
+//
+// Zero-factor shortcut: after zero-padding and the usual bit-reversal
+// permutation, every size-`blowup` block of the working array holds
+// exactly one nonzero element, sitting at the block's first slot (the
+// `log2(blowup)` leading bits a coefficient index never sets land in the
+// low bits of its bit-reversed position). The first `log2(blowup)`
+// butterfly stages would only ever combine that one nonzero value against
+// zero, so they degenerate to broadcasting it across the block; this does
+// that broadcast directly and starts the real butterfly stages above it.
+fn fp_lde_poly(coeffs: &[Felt], big_n: usize, shift: &Felt) -> Option<Vec<Felt>> {
+    let n = coeffs.len();
+    if n == 0 || n & (n - 1) != 0 || big_n & (big_n - 1) != 0 || big_n < n {
+        return None;
+    }
+
+    let log_n = n.trailing_zeros() as usize;
+    let log_big_n = big_n.trailing_zeros() as usize;
+    if log_big_n >= ROOTS.len() {
+        return None;
+    }
+    let log_blowup = log_big_n - log_n;
+    let blowup = 1usize << log_blowup;
+
+    let mut a = vec![Felt::zero(); big_n];
+    if felt_eq(shift, &Felt::one()) {
+        a[..n].copy_from_slice(coeffs);
+    } else {
+        let mut power = Felt::one();
+        for (i, c) in coeffs.iter().enumerate() {
+            fmul(c, &power, &mut a[i]);
+            let mut next = Felt::zero();
+            fmul(&power, shift, &mut next);
+            power = next;
+        }
+    }
+
+    for i in 0..big_n {
+        let r = bit_reverse(i, log_big_n);
+        if r > i {
+            a.swap(i, r);
+        }
+    }
+
+    if blowup > 1 {
+        let mut k = 0;
+        while k < big_n {
+            let v = a[k];
+            for j in 1..blowup {
+                a[k + j] = v;
+            }
+            k += blowup;
+        }
+    }
+
+    let root = get_root_of_unity(log_big_n);
+    let table = FftRootTable::new(log_big_n, root);
+    fft_butterflies(&mut a, &table, log_blowup, log_big_n);
+
+    Some(a)
+}
+
+// interpolate_jet: Lagrange interpolation, with a fast path for the
+// roots-of-unity domains STARK/FRI actually interpolate over
 pub fn interpolate_jet(context: &mut Context, subject: Noun) -> Result {
     let sam = slot(subject, 6)?;
     let domain = slot(sam, 2)?;
     let values = slot(sam, 3)?;
 
-    let (Ok(domain_poly), Ok(values_poly)) = 
+    let (Ok(domain_poly), Ok(values_poly)) =
         (FPolySlice::try_from(domain), FPolySlice::try_from(values)) else {
         return jet_err();
     };
@@ -359,13 +488,57 @@ pub fn interpolate_jet(context: &mut Context, subject: Noun) -> Result {
     let len = domain_poly.len();
     let (res, res_poly): (IndirectAtom, &mut [Felt]) =
         new_handle_mut_slice(&mut context.stack, Some(len));
-    
-    interpolate_poly(domain_poly.data(), values_poly.data(), res_poly);
+
+    // If `domain` is exactly the `n`-th roots of unity in evaluation order
+    // (`domain[i] == root^i`), interpolation is just an inverse NTT of
+    // `values` - O(n log n) instead of the general path's O(n^3).
+    if roots_of_unity_log_n(domain_poly.data()).is_some() {
+        fp_ifft_poly(values_poly.data(), res_poly);
+    } else {
+        interpolate_poly(domain_poly.data(), values_poly.data(), res_poly);
+    }
 
     let res_cell = finalize_poly(&mut context.stack, Some(res_poly.len()), res);
     Ok(res_cell)
 }
 
+// Field-wise equality on `Felt`'s three limbs, since the type doesn't
+// derive `PartialEq` itself (existing code compares limbs directly, e.g.
+// `fpoly_to_list`'s `felt.0[0].0`).
+fn felt_eq(a: &Felt, b: &Felt) -> bool {
+    a.0[0].0 == b.0[0].0 && a.0[1].0 == b.0[1].0 && a.0[2].0 == b.0[2].0
+}
+
+// `Some(log_n)` iff `domain` is exactly `[root^0, root^1, ..., root^(n-1)]`
+// for `root = get_root_of_unity(log_n)` and `n = domain.len()` a power of
+// two within the precomputed root table's range; `None` for any other
+// domain (including non-power-of-two lengths), so the caller falls back to
+// general Lagrange interpolation.
+fn roots_of_unity_log_n(domain: &[Felt]) -> Option<usize> {
+    let n = domain.len();
+    if n == 0 || n & (n - 1) != 0 {
+        return None;
+    }
+
+    let log_n = n.trailing_zeros() as usize;
+    if log_n >= ROOTS.len() {
+        return None;
+    }
+
+    let root = get_root_of_unity(log_n);
+    let mut power = Felt::one();
+    for expected in domain.iter() {
+        if !felt_eq(expected, &power) {
+            return None;
+        }
+        let mut next = Felt::zero();
+        fmul(&power, &root, &mut next);
+        power = next;
+    }
+
+    Some(log_n)
+}
+
 // fpcompose_jet: Polynomial composition P(Q(X))
 pub fn fpcompose_jet(context: &mut Context, subject: Noun) -> Result {
     let sam = slot(subject, 6)?;
@@ -392,6 +565,70 @@ pub fn fpcompose_jet(context: &mut Context, subject: Noun) -> Result {
     Ok(res_cell)
 }
 
+// fp_divmod_jet: polynomial long division, returning `[quotient remainder]`
+pub fn fp_divmod_jet(context: &mut Context, subject: Noun) -> Result {
+    let sam = slot(subject, 6)?;
+    let p = slot(sam, 2)?;
+    let q = slot(sam, 3)?;
+
+    let (Ok(p_poly), Ok(q_poly)) = (FPolySlice::try_from(p), FPolySlice::try_from(q)) else {
+        return jet_err();
+    };
+
+    let Some((quotient, remainder)) = fpdivmod_poly(p_poly.data(), q_poly.data()) else {
+        return jet_err();
+    };
+
+    let q_cell = poly_vec_to_cell(context, &quotient);
+    let r_cell = poly_vec_to_cell(context, &remainder);
+
+    Ok(T(&mut context.stack, &[q_cell, r_cell]))
+}
+
+// fp_div_jet: polynomial division, quotient only
+pub fn fp_div_jet(context: &mut Context, subject: Noun) -> Result {
+    let sam = slot(subject, 6)?;
+    let p = slot(sam, 2)?;
+    let q = slot(sam, 3)?;
+
+    let (Ok(p_poly), Ok(q_poly)) = (FPolySlice::try_from(p), FPolySlice::try_from(q)) else {
+        return jet_err();
+    };
+
+    let Some((quotient, _remainder)) = fpdivmod_poly(p_poly.data(), q_poly.data()) else {
+        return jet_err();
+    };
+
+    Ok(poly_vec_to_cell(context, &quotient))
+}
+
+// fp_mod_jet: polynomial division, remainder only
+pub fn fp_mod_jet(context: &mut Context, subject: Noun) -> Result {
+    let sam = slot(subject, 6)?;
+    let p = slot(sam, 2)?;
+    let q = slot(sam, 3)?;
+
+    let (Ok(p_poly), Ok(q_poly)) = (FPolySlice::try_from(p), FPolySlice::try_from(q)) else {
+        return jet_err();
+    };
+
+    let Some((_quotient, remainder)) = fpdivmod_poly(p_poly.data(), q_poly.data()) else {
+        return jet_err();
+    };
+
+    Ok(poly_vec_to_cell(context, &remainder))
+}
+
+// Copies `poly` into a freshly allocated handle and wraps it as a Hoon
+// `fpoly` cell, the same `new_handle_mut_slice` + `finalize_poly` pattern
+// every other jet in this file uses for its single return value.
+fn poly_vec_to_cell(context: &mut Context, poly: &[Felt]) -> Noun {
+    let (res, res_poly): (IndirectAtom, &mut [Felt]) =
+        new_handle_mut_slice(&mut context.stack, Some(poly.len()));
+    res_poly.copy_from_slice(poly);
+    finalize_poly(&mut context.stack, Some(res_poly.len()), res)
+}
+
 // ============================================================================
 // Field polynomial math operations
 // ============================================================================
@@ -460,7 +697,23 @@ fn fpscal_poly(c: &Felt, p: &[Felt], res: &mut [Felt]) {
     }
 }
 
-// Field polynomial multiplication (naive O(n²) algorithm)
+// FFT convolution is used only when both operands are longer than this;
+// below that, FFT setup (padding to a power of two, two forward transforms,
+// a pointwise multiply, one inverse transform) costs more than the
+// convolution it would replace.
+const FPMUL_FFT_THRESHOLD: usize = 64;
+
+// Below this length, Karatsuba's constant-factor overhead (building the
+// sum operands, three recursive calls) costs more than just running the
+// schoolbook loop.
+const FPMUL_KARATSUBA_THRESHOLD: usize = 24;
+
+// Field polynomial multiplication: dispatches to FFT convolution once both
+// operands are long enough to make the O(n log n) path worth its setup
+// cost, Karatsuba in the wide band below that where it beats schoolbook,
+// and falls back to the naive O(n^2) path for small inputs. If the padded
+// FFT transform length would exceed the precomputed root-of-unity table,
+// Karatsuba runs instead (both operands are then above its cutoff too).
 fn fpmul_poly(p: &[Felt], q: &[Felt], res: &mut [Felt]) {
     let lp = p.len();
     let lq = q.len();
@@ -469,6 +722,113 @@ fn fpmul_poly(p: &[Felt], q: &[Felt], res: &mut [Felt]) {
         return;
     }
 
+    if lp > FPMUL_FFT_THRESHOLD && lq > FPMUL_FFT_THRESHOLD && fpmul_poly_fft(p, q, res) {
+        return;
+    }
+
+    if lp > FPMUL_KARATSUBA_THRESHOLD && lq > FPMUL_KARATSUBA_THRESHOLD {
+        fpkaratsuba_poly(p, q, res);
+        return;
+    }
+
+    fpmul_poly_naive(p, q, res);
+}
+
+// Splits `p` into its low `k` coefficients and everything above, so
+// `p == p0 + x^k * p1`. Returns an empty high half if `p` is shorter than
+// `k` (the two operands of a Karatsuba split need not be the same length).
+fn split_poly_at(p: &[Felt], k: usize) -> (&[Felt], &[Felt]) {
+    if p.len() <= k {
+        (p, &[])
+    } else {
+        (&p[..k], &p[k..])
+    }
+}
+
+// Length of the product of two polynomials of the given lengths (0 if
+// either operand is empty).
+fn poly_mul_len(lp: usize, lq: usize) -> usize {
+    if lp == 0 || lq == 0 {
+        0
+    } else {
+        lp + lq - 1
+    }
+}
+
+// Karatsuba polynomial multiplication: split each operand at `k =
+// max(lp, lq) / 2` into low/high halves, compute the two "diagonal"
+// half-size products `z0 = p0*q0` and `z2 = p1*q1` plus the "cross" product
+// `z1 = (p0+p1)*(q0+q1) - z0 - z2` (three recursive half-size
+// multiplications instead of four), and combine as
+// `res = z0 + x^k*z1 + x^(2k)*z2`. Recurses down to the naive schoolbook
+// loop once either operand drops to `FPMUL_KARATSUBA_THRESHOLD` or below.
+fn fpkaratsuba_poly(p: &[Felt], q: &[Felt], res: &mut [Felt]) {
+    let lp = p.len();
+    let lq = q.len();
+
+    if lp <= FPMUL_KARATSUBA_THRESHOLD || lq <= FPMUL_KARATSUBA_THRESHOLD {
+        fpmul_poly_naive(p, q, res);
+        return;
+    }
+
+    let k = std::cmp::max(lp, lq) / 2;
+    let (p0, p1) = split_poly_at(p, k);
+    let (q0, q1) = split_poly_at(q, k);
+
+    let mut z0 = vec![Felt::zero(); poly_mul_len(p0.len(), q0.len())];
+    if !z0.is_empty() {
+        fpkaratsuba_poly(p0, q0, &mut z0);
+    }
+
+    let mut z2 = vec![Felt::zero(); poly_mul_len(p1.len(), q1.len())];
+    if !z2.is_empty() {
+        fpkaratsuba_poly(p1, q1, &mut z2);
+    }
+
+    let mut sum_p = vec![Felt::zero(); std::cmp::max(p0.len(), p1.len())];
+    fpadd_poly(p0, p1, &mut sum_p);
+    let mut sum_q = vec![Felt::zero(); std::cmp::max(q0.len(), q1.len())];
+    fpadd_poly(q0, q1, &mut sum_q);
+
+    let mut z1 = vec![Felt::zero(); poly_mul_len(sum_p.len(), sum_q.len())];
+    if !z1.is_empty() {
+        fpkaratsuba_poly(&sum_p, &sum_q, &mut z1);
+    }
+    for i in 0..z0.len() {
+        let temp = z1[i];
+        fsub(&temp, &z0[i], &mut z1[i]);
+    }
+    for i in 0..z2.len() {
+        let temp = z1[i];
+        fsub(&temp, &z2[i], &mut z1[i]);
+    }
+
+    for slot in res.iter_mut() {
+        *slot = Felt::zero();
+    }
+    for (i, term) in z0.iter().enumerate() {
+        let temp = res[i];
+        fadd(term, &temp, &mut res[i]);
+    }
+    for (i, term) in z1.iter().enumerate() {
+        if let Some(slot) = res.get(i + k) {
+            let temp = *slot;
+            fadd(term, &temp, &mut res[i + k]);
+        }
+    }
+    for (i, term) in z2.iter().enumerate() {
+        if let Some(slot) = res.get(i + 2 * k) {
+            let temp = *slot;
+            fadd(term, &temp, &mut res[i + 2 * k]);
+        }
+    }
+}
+
+// Naive O(n^2) schoolbook convolution.
+fn fpmul_poly_naive(p: &[Felt], q: &[Felt], res: &mut [Felt]) {
+    let lp = p.len();
+    let lq = q.len();
+
     // Initialize result to zero
     for i in 0..res.len() {
         res[i] = Felt::zero();
@@ -485,6 +845,107 @@ fn fpmul_poly(p: &[Felt], q: &[Felt], res: &mut [Felt]) {
     }
 }
 
+// FFT convolution: zero-pad both operands to the same power-of-two length
+// `n`, evaluate each at the `n`-th roots of unity via `fp_fft_poly`,
+// multiply pointwise, and interpolate back via `fp_ifft_poly`. Both
+// transforms share the same `n` (and hence the same root of unity), which
+// is the invariant that makes the pointwise product correspond to a
+// circular convolution containing the true (linear) one in its first `m`
+// coefficients. Returns `false` without touching `res` if `n` exceeds the
+// precomputed root-of-unity table, so the caller can fall back to Karatsuba.
+fn fpmul_poly_fft(p: &[Felt], q: &[Felt], res: &mut [Felt]) -> bool {
+    let m = p.len() + q.len() - 1;
+    let n = m.next_power_of_two();
+    let log_n = n.trailing_zeros() as usize;
+
+    if log_n >= ROOTS.len() {
+        return false;
+    }
+
+    // Both operands transform at the same size `n`, so they share one root
+    // table instead of each forward call rebuilding it.
+    let root = get_root_of_unity(log_n);
+    let table = FftRootTable::new(log_n, root);
+
+    let mut p_evals = vec![Felt::zero(); n];
+    p_evals[..p.len()].copy_from_slice(p);
+    fft_in_place(&mut p_evals, &table);
+
+    let mut q_evals = vec![Felt::zero(); n];
+    q_evals[..q.len()].copy_from_slice(q);
+    fft_in_place(&mut q_evals, &table);
+
+    let mut prod_evals = vec![Felt::zero(); n];
+    for i in 0..n {
+        fmul(&p_evals[i], &q_evals[i], &mut prod_evals[i]);
+    }
+
+    let mut inv_root = Felt::zero();
+    finv(&root, &mut inv_root);
+    let inv_table = FftRootTable::new(log_n, inv_root);
+    fft_in_place(&mut prod_evals, &inv_table);
+
+    let n_felt = Felt::from([Belt(n as u64), Belt(0), Belt(0)]);
+    let mut inv_n = Felt::zero();
+    finv(&n_felt, &mut inv_n);
+
+    for i in 0..m {
+        fmul(&prod_evals[i], &inv_n, &mut res[i]);
+    }
+    true
+}
+
+// Highest index holding a nonzero coefficient, or `None` if `p` is the zero
+// polynomial (including the empty slice).
+fn poly_degree(p: &[Felt]) -> Option<usize> {
+    let zero = Felt::zero();
+    (0..p.len()).rev().find(|&i| !felt_eq(&p[i], &zero))
+}
+
+// Classic schoolbook long division: `p = q*quotient + remainder` with
+// `deg(remainder) < deg(q)`. `None` if `q` is the zero polynomial (no
+// well-defined leading coefficient to normalize by). Works from the top
+// degree down, at each step cancelling the working remainder's current
+// leading term against `q`'s, exactly as described for the jet.
+fn fpdivmod_poly(p: &[Felt], q: &[Felt]) -> Option<(Vec<Felt>, Vec<Felt>)> {
+    let q_deg = poly_degree(q)?;
+    let q_trimmed = &q[..=q_deg];
+
+    let mut inv_lead_q = Felt::zero();
+    finv(&q_trimmed[q_deg], &mut inv_lead_q);
+
+    let mut remainder: Vec<Felt> = p.to_vec();
+
+    let Some(p_deg) = poly_degree(p) else {
+        return Some((Vec::new(), Vec::new()));
+    };
+
+    if p_deg < q_deg {
+        return Some((Vec::new(), remainder));
+    }
+
+    let quotient_len = p_deg - q_deg + 1;
+    let mut quotient = vec![Felt::zero(); quotient_len];
+
+    for shift in (0..quotient_len).rev() {
+        let rem_coeff = remainder[q_deg + shift];
+        let mut coeff = Felt::zero();
+        fmul(&rem_coeff, &inv_lead_q, &mut coeff);
+        quotient[shift] = coeff;
+
+        for (j, qj) in q_trimmed.iter().enumerate() {
+            let idx = shift + j;
+            let mut term = Felt::zero();
+            fmul(&coeff, qj, &mut term);
+            let temp = remainder[idx];
+            fsub(&temp, &term, &mut remainder[idx]);
+        }
+    }
+
+    remainder.truncate(q_deg);
+    Some((quotient, remainder))
+}
+
 // Evaluate polynomial at a point using Horner's method
 fn fpeval_poly(p: &[Felt], x: &Felt) -> Felt {
     if p.is_empty() {
@@ -502,21 +963,24 @@ fn fpeval_poly(p: &[Felt], x: &Felt) -> Felt {
     result
 }
 
+// These are the same precomputed roots from the Hoon code. Shared between
+// `get_root_of_unity` and `fpmul_poly`'s FFT-convolution path, which needs
+// the table size to know when a requested transform length is too large to
+// serve and has to fall back to Karatsuba instead.
+const ROOTS: &[u64] = &[
+    0x0000000000000001, 0xffffffff00000000, 0x0001000000000000, 0xfffffffeff000001,
+    0xefffffff00000001, 0x00003fffffffc000, 0x0000008000000000, 0xf80007ff08000001,
+    0xbf79143ce60ca966, 0x1905d02a5c411f4e, 0x9d8f2ad78bfed972, 0x0653b4801da1c8cf,
+    0xf2c35199959dfcb6, 0x1544ef2335d17997, 0xe0ee099310bba1e2, 0xf6b2cffe2306baac,
+    0x54df9630bf79450e, 0xabd0a6e8aa3d8a0e, 0x81281a7b05f9beac, 0xfbd41c6b8caa3302,
+    0x30ba2ecd5e93e76d, 0xf502aef532322654, 0x4b2a18ade67246b5, 0xea9d5a1336fbc98b,
+    0x86cdcc31c307e171, 0x4bbaf5976ecfefd8, 0xed41d05b78d6e286, 0x10d78dd8915a171d,
+    0x59049500004a4485, 0xdfa8c93ba46d2666, 0x7e9bd009b86a0845, 0x400a7f755588e659,
+    0x185629dcda58878c,
+];
+
 // Helper function to get root of unity for given log size
 fn get_root_of_unity(log_n: usize) -> Felt {
-    // These are the same precomputed roots from the Hoon code
-    const ROOTS: &[u64] = &[
-        0x0000000000000001, 0xffffffff00000000, 0x0001000000000000, 0xfffffffeff000001,
-        0xefffffff00000001, 0x00003fffffffc000, 0x0000008000000000, 0xf80007ff08000001,
-        0xbf79143ce60ca966, 0x1905d02a5c411f4e, 0x9d8f2ad78bfed972, 0x0653b4801da1c8cf,
-        0xf2c35199959dfcb6, 0x1544ef2335d17997, 0xe0ee099310bba1e2, 0xf6b2cffe2306baac,
-        0x54df9630bf79450e, 0xabd0a6e8aa3d8a0e, 0x81281a7b05f9beac, 0xfbd41c6b8caa3302,
-        0x30ba2ecd5e93e76d, 0xf502aef532322654, 0x4b2a18ade67246b5, 0xea9d5a1336fbc98b,
-        0x86cdcc31c307e171, 0x4bbaf5976ecfefd8, 0xed41d05b78d6e286, 0x10d78dd8915a171d,
-        0x59049500004a4485, 0xdfa8c93ba46d2666, 0x7e9bd009b86a0845, 0x400a7f755588e659,
-        0x185629dcda58878c,
-    ];
-    
     assert!(log_n < ROOTS.len(), "FFT size too large");
     Felt::from([Belt(ROOTS[log_n]), Belt(0), Belt(0)])
 }
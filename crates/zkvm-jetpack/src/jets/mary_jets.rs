@@ -7,8 +7,11 @@ use nockvm::jets::math::util::add;
 use nockvm::mem::NockStack;
 use tracing::{debug, info, error};
 
+use bitvec::prelude::{BitSlice, Lsb0};
+
 use crate::form::mary::*;
 use crate::form::math::mary::*;
+use crate::form::Belt;
 use crate::hand::handle::{finalize_mary, new_handle_mut_mary};
 use crate::jets::utils::jet_err;
 
@@ -114,3 +117,116 @@ pub fn mary_transpose_jet(context: &mut Context, subject: Noun) -> Result<Noun,
 
     Ok(res_cell)
 }
+
+// Packed wire/on-disk format for a `mary`: a fixed 9-byte header - `step`
+// (u32 LE), `len` (u32 LE), element width in bytes (u8, always `BELT_WIDTH`
+// for a `Belt`) - followed by `step * len` little-endian `u64` limbs with
+// no inter-element padding. Modeled explicitly like a packed struct so the
+// layout is obvious from the constants below rather than implicit in the
+// read/write code.
+const MARY_HEADER_LEN: usize = 9;
+const BELT_WIDTH: u8 = 8;
+
+/// Encodes `ma` into the packed format above, returning it as a single
+/// byte atom - the `mary`/`bpoly` equivalent of `Atom::new_raw_bytes`, a
+/// flat layout instead of a noun tree, so the same bytes are portable
+/// across a checkpoint or the wire without re-walking cons cells.
+pub fn mary_to_bytes_jet(context: &mut Context, subject: Noun) -> Result<Noun, JetErr> {
+    let ma = slot(subject, 6)?;
+    let Ok(mary) = MarySlice::try_from(ma) else {
+        debug!("mary_to_bytes: sample is not a mary");
+        return jet_err();
+    };
+
+    let mut bytes =
+        Vec::with_capacity(MARY_HEADER_LEN + mary.dat.len() * BELT_WIDTH as usize);
+    bytes.extend_from_slice(&(mary.step as u32).to_le_bytes());
+    bytes.extend_from_slice(&(mary.len as u32).to_le_bytes());
+    bytes.push(BELT_WIDTH);
+    for belt in mary.dat.iter() {
+        bytes.extend_from_slice(&belt.0.to_le_bytes());
+    }
+
+    let res_atom =
+        unsafe { IndirectAtom::new_raw_bytes(&mut context.stack, bytes.len(), bytes.as_ptr()) };
+
+    Ok(res_atom.as_noun())
+}
+
+/// Reads byte `i` (little-endian, word-aligned) out of an atom's
+/// underlying bitslice, or `0` past its highest set bit - the implicit
+/// zero-extension every nock atom has above its minimal representation.
+fn read_packed_byte(bits: &BitSlice<u64, Lsb0>, i: usize) -> u8 {
+    let mut byte = 0u8;
+    for bit in 0..8 {
+        let pos = i * 8 + bit;
+        if pos < bits.len() && bits[pos] {
+            byte |= 1u8 << bit;
+        }
+    }
+    byte
+}
+
+/// Decodes a byte atom produced by `mary_to_bytes_jet` back into a `mary`.
+/// Bounds-checked against the header it claims to have: a buffer shorter
+/// than the 9-byte header, or one whose significant bytes run past the
+/// header-declared `step * len * element_width`, is rejected rather than
+/// silently truncated or read out of range. A buffer that's short *within*
+/// the declared body is not an error - a nock atom has no trailing zero
+/// bytes of its own, so that's the normal shape for a payload whose
+/// high-order bytes happen to be zero, and `read_packed_byte` zero-extends
+/// for exactly that case.
+pub fn mary_from_bytes_jet(context: &mut Context, subject: Noun) -> Result<Noun, JetErr> {
+    let packed = slot(subject, 6)?;
+    let Ok(atom) = packed.as_atom() else {
+        debug!("mary_from_bytes: sample is not an atom");
+        return jet_err();
+    };
+
+    let bits = atom.as_bitslice();
+    let actual_len = (bits.len() + 7) / 8;
+    if actual_len < MARY_HEADER_LEN {
+        debug!("mary_from_bytes: buffer shorter than the header");
+        return jet_err();
+    }
+
+    let mut header = [0u8; MARY_HEADER_LEN];
+    for (i, byte) in header.iter_mut().enumerate() {
+        *byte = read_packed_byte(bits, i);
+    }
+    let step = u32::from_le_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    let len = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+    let element_width = header[8];
+    if element_width != BELT_WIDTH {
+        debug!("mary_from_bytes: unsupported element width {}", element_width);
+        return jet_err();
+    }
+
+    let Some(body_len) = step
+        .checked_mul(len)
+        .and_then(|n| n.checked_mul(BELT_WIDTH as usize))
+    else {
+        debug!("mary_from_bytes: step * len * element_width overflows");
+        return jet_err();
+    };
+    let expected_len = MARY_HEADER_LEN + body_len;
+    if actual_len > expected_len {
+        debug!("mary_from_bytes: buffer longer than the header declares");
+        return jet_err();
+    }
+
+    let (res, res_poly): (IndirectAtom, MarySliceMut) =
+        new_handle_mut_mary(&mut context.stack, step, len);
+    for (i, belt) in res_poly.dat.iter_mut().enumerate() {
+        let elem_offset = MARY_HEADER_LEN + i * BELT_WIDTH as usize;
+        let mut limb = [0u8; 8];
+        for (j, b) in limb.iter_mut().enumerate() {
+            *b = read_packed_byte(bits, elem_offset + j);
+        }
+        *belt = Belt(u64::from_le_bytes(limb));
+    }
+
+    let res_cell = finalize_mary(&mut context.stack, step, len, res);
+
+    Ok(res_cell)
+}
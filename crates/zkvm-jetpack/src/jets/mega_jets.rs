@@ -22,6 +22,16 @@ fn ones_bpoly(len: usize) -> BPolyVec {
     BPolyVec::from(vec![1u64; len])
 }
 
+/// Elementwise `base[i]^exp`, via `bpow`'s fast exponentiation-by-squaring
+/// per element. Used in place of `exp` repeated full-vector `bp_hadamard`
+/// passes below: since `base` is held fixed across the loop, `exp` rounds
+/// of `acc = acc .* base` are exactly `acc .* base^exp`, so computing the
+/// power once and doing a single Hadamard product turns an `O(exp * n)`
+/// walk into `O(n log exp)`.
+fn bp_pow_elementwise(base: &[Belt], exp: u64) -> BPolyVec {
+    BPolyVec::from(base.iter().map(|b| bpow(b.0, exp)).collect::<Vec<u64>>())
+}
+
 pub fn mp_substitute_mega_jet(context: &mut Context, subject: Noun) -> Result {
     let sam = slot(subject, 6)?;
     let stack = &mut context.stack;
@@ -86,15 +96,13 @@ pub fn mp_substitute_mega_jet(context: &mut Context, subject: Noun) -> Result {
                     }
                     let var_slice = &trace_evals.0[var_start_idx..var_end_idx];
 
-                    let hadamard_res_len = inner_acc_vec.len().min(var_slice.len());
+                    let var_pow_vec = bp_pow_elementwise(var_slice, exp);
+                    let hadamard_res_len = inner_acc_vec.len().min(var_pow_vec.len());
                     let (_res_atom, res_poly_slice): (IndirectAtom, &mut [Belt]) =
                         new_handle_mut_slice(stack, Some(hadamard_res_len));
 
-                    for _ in 0..exp {
-                        let current_inner_acc_slice = &inner_acc_vec.0;
-                        bp_hadamard(current_inner_acc_slice, var_slice, res_poly_slice);
-                        inner_acc_vec = BPolyVec::from(res_poly_slice.iter().map(|&b| b.0).collect::<Vec<u64>>());
-                    }
+                    bp_hadamard(&inner_acc_vec.0, &var_pow_vec.0, res_poly_slice);
+                    inner_acc_vec = BPolyVec::from(res_poly_slice.iter().map(|&b| b.0).collect::<Vec<u64>>());
                 }
                 MegaTyp::Rnd => {
                     let rnd_noun = chal_map.get(stack, D(idx as u64)).ok_or_else(|| jet_err::<()>().unwrap_err())?;
@@ -128,15 +136,13 @@ pub fn mp_substitute_mega_jet(context: &mut Context, subject: Noun) -> Result {
                         return jet_err::<()>();
                     };
 
-                    let hadamard_res_len = inner_acc_vec.len().min(com_slice.len());
+                    let com_pow_vec = bp_pow_elementwise(com_slice.0, exp);
+                    let hadamard_res_len = inner_acc_vec.len().min(com_pow_vec.len());
                     let (_res_atom, res_poly_slice): (IndirectAtom, &mut [Belt]) =
                         new_handle_mut_slice(stack, Some(hadamard_res_len));
 
-                    for _ in 0..exp {
-                        let current_inner_acc_slice = &inner_acc_vec.0;
-                        bp_hadamard(current_inner_acc_slice, com_slice.0, res_poly_slice);
-                        inner_acc_vec = BPolyVec::from(res_poly_slice.iter().map(|&b| b.0).collect::<Vec<u64>>());
-                    }
+                    bp_hadamard(&inner_acc_vec.0, &com_pow_vec.0, res_poly_slice);
+                    inner_acc_vec = BPolyVec::from(res_poly_slice.iter().map(|&b| b.0).collect::<Vec<u64>>());
                 }
             }
         }
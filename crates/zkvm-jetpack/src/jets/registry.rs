@@ -0,0 +1,153 @@
+//! Code-generated, index-dispatched jet registry.
+//!
+//! Every jet in this crate used to be its own free function, hand-matched
+//! by name wherever it needed to be dispatched. This module replaces that
+//! with a single declarative list: `jet_table!` takes `(variant, hoon
+//! name, function)` entries and emits a `#[repr(u8)] JetId` enum, a
+//! `JET_COUNT`, a bounds-checked `TryFrom<u8>`, and a `JET_DISPATCH` array
+//! indexed by discriminant. Dispatching a jet by id is then an O(1) array
+//! index instead of a string match, and adding a jet to the list is the
+//! only step required to wire it in — there's no separate dispatch table
+//! to remember to update.
+//!
+//! This is the instruction-table pattern from bytecode VM design (a
+//! generated name/count table plus a checked numeric decode) applied to
+//! this crate's jet surface.
+//!
+//! `dispatch_by_name` is the entry point meant to replace an existing
+//! by-name match: it's a linear scan over `JET_NAMES` today (this crate has
+//! no stable numeric jet id carried in from the interpreter side yet, only
+//! the name), but it's the one place that scan needs to live — every other
+//! caller should go through it, or `dispatch`, rather than re-matching jet
+//! names itself. The interpreter-side hook that currently does its own
+//! by-name match and would call this instead isn't part of this crate's
+//! snapshot, so that call-site swap is still outstanding; this module is
+//! the replacement those call sites should be pointed at.
+
+use nockvm::interpreter::Context;
+use nockvm::jets::Result;
+use nockvm::noun::Noun;
+
+use crate::jets::bp_jets;
+use crate::jets::fp_jets;
+use crate::jets::mary_jets;
+use crate::jets::mega_jets;
+use crate::jets::tip5_jets;
+
+macro_rules! jet_table {
+    ( $( $variant:ident ( $name:literal ) => $func:path ),+ $(,)? ) => {
+        /// One discriminant per registered jet, in table order. The
+        /// discriminants are the default `0, 1, 2, ...` Rust assigns a
+        /// fieldless `#[repr(u8)]` enum, which is exactly what
+        /// `TryFrom<u8>` below relies on.
+        #[repr(u8)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum JetId {
+            $( $variant, )+
+        }
+
+        /// Number of registered jets; also the length of `JET_DISPATCH`
+        /// and `JET_NAMES`.
+        #[allow(clippy::let_and_return)]
+        pub const JET_COUNT: u8 = {
+            let mut count: u8 = 0;
+            $( let _ = JetId::$variant; count += 1; )+
+            count
+        };
+
+        impl TryFrom<u8> for JetId {
+            type Error = ();
+
+            /// Bounds-checked numeric decode: `value` is only ever
+            /// transmuted once it's known to be a valid discriminant.
+            fn try_from(value: u8) -> core::result::Result<Self, Self::Error> {
+                if value < JET_COUNT {
+                    // Safety: `JetId` is `#[repr(u8)]` with discriminants
+                    // `0..JET_COUNT` assigned in declaration order, and
+                    // `value` was just checked to fall within that range.
+                    Ok(unsafe { core::mem::transmute::<u8, JetId>(value) })
+                } else {
+                    Err(())
+                }
+            }
+        }
+
+        /// Hoon-facing jet name for each `JetId`, in discriminant order.
+        pub static JET_NAMES: [&str; JET_COUNT as usize] = [
+            $( $name, )+
+        ];
+
+        /// Dispatch table: `JET_DISPATCH[id as usize]` is the jet function
+        /// for `id`.
+        pub static JET_DISPATCH: [fn(&mut Context, Noun) -> Result; JET_COUNT as usize] = [
+            $( $func, )+
+        ];
+    };
+}
+
+jet_table! {
+    BpolyToList("bpoly-to-list") => bp_jets::bpoly_to_list_jet,
+    Bpadd("bpadd") => bp_jets::bpadd_jet,
+    Bpneg("bpneg") => bp_jets::bpneg_jet,
+    Bpsub("bpsub") => bp_jets::bpsub_jet,
+    Bpscal("bpscal") => bp_jets::bpscal_jet,
+    Bpmul("bpmul") => bp_jets::bpmul_jet,
+    BpHadamard("bp-hadamard") => bp_jets::bp_hadamard_jet,
+    BpNtt("bp-ntt") => bp_jets::bp_ntt_jet,
+    BpFft("bp-fft") => bp_jets::bp_fft_jet,
+    BpIfft("bp-ifft") => bp_jets::bp_ifft_jet,
+    BpShift("bp-shift") => bp_jets::bp_shift_jet,
+    BpCoseword("bp-coseword") => bp_jets::bp_coseword_jet,
+    TurnCoseword("turn-coseword") => bp_jets::turn_coseword_jet,
+    InitBpoly("init-bpoly") => bp_jets::init_bpoly_jet,
+    MaryCosetLde("mary-coset-lde") => bp_jets::mary_coset_lde_jet,
+
+    FpolyToList("fpoly-to-list") => fp_jets::fpoly_to_list_jet,
+    FpAdd("fp-add") => fp_jets::fp_add_jet,
+    FpNeg("fp-neg") => fp_jets::fp_neg_jet,
+    FpSub("fp-sub") => fp_jets::fp_sub_jet,
+    FpScal("fp-scal") => fp_jets::fp_scal_jet,
+    FpMul("fp-mul") => fp_jets::fp_mul_jet,
+    FpEval("fp-eval") => fp_jets::fp_eval_jet,
+    FpFft("fp-fft") => fp_jets::fp_fft_jet,
+    FpIfft("fp-ifft") => fp_jets::fp_ifft_jet,
+    FpLde("fp-lde") => fp_jets::fp_lde_jet,
+    Interpolate("interpolate") => fp_jets::interpolate_jet,
+    Fpcompose("fpcompose") => fp_jets::fpcompose_jet,
+    FpDivmod("fp-divmod") => fp_jets::fp_divmod_jet,
+    FpDiv("fp-div") => fp_jets::fp_div_jet,
+    FpMod("fp-mod") => fp_jets::fp_mod_jet,
+
+    MarySwag("mary-swag") => mary_jets::mary_swag_jet,
+    TransposeBpolys("transpose-bpolys") => mary_jets::transpose_bpolys_jet,
+    MaryWeld("mary-weld") => mary_jets::mary_weld_jet,
+    MaryTranspose("mary-transpose") => mary_jets::mary_transpose_jet,
+    MaryToBytes("mary-to-bytes") => mary_jets::mary_to_bytes_jet,
+    MaryFromBytes("mary-from-bytes") => mary_jets::mary_from_bytes_jet,
+
+    MpSubstituteMega("mp-substitute-mega") => mega_jets::mp_substitute_mega_jet,
+
+    Permutation("permutation") => tip5_jets::permutation_jet,
+    HashVarlen("hash-varlen") => tip5_jets::hash_varlen_jet,
+    HashVarlenBatch("hash-varlen-batch") => tip5_jets::hash_varlen_batch_jet,
+    PermuteBatch("permute-batch") => tip5_jets::permute_batch_jet,
+    Montify("montify") => tip5_jets::montify_jet,
+    Montiply("montiply") => tip5_jets::montiply_jet,
+    MontReduction("mont-reduction") => tip5_jets::mont_reduction_jet,
+}
+
+/// Looks up and runs the jet for `id`, if it's a registered discriminant.
+pub fn dispatch(id: u8, context: &mut Context, subject: Noun) -> Option<Result> {
+    let id = JetId::try_from(id).ok()?;
+    Some(JET_DISPATCH[id as usize](context, subject))
+}
+
+/// Looks up and runs the jet registered under `name` (the Hoon-facing jet
+/// name, e.g. `"bpadd"`), if any. This is the by-name counterpart of
+/// `dispatch`, for callers that only have the name on hand rather than an
+/// already-resolved `JetId` — the shape a hand-written string match would
+/// have taken before this table existed.
+pub fn dispatch_by_name(name: &str, context: &mut Context, subject: Noun) -> Option<Result> {
+    let id = JET_NAMES.iter().position(|&n| n == name)?;
+    Some(JET_DISPATCH[id](context, subject))
+}
@@ -2,11 +2,12 @@ use nockvm::interpreter::Context;
 use nockvm::jets::list::util::lent;
 use nockvm::jets::util::slot;
 use nockvm::jets::JetErr;
-use nockvm::noun::{Noun};
+use nockvm::noun::{Noun, D, T};
 
 use crate::based;
 use crate::form::math::tip5::*;
 use crate::form::{Belt, Poly};
+use crate::jets::tip5_simd;
 use crate::jets::utils::jet_err;
 
 use bitvec::prelude::{BitSlice, Lsb0};
@@ -49,25 +50,45 @@ pub fn permutation_jet(context: &mut Context, subject: Noun) -> Result<Noun, Jet
 
 pub fn hash_varlen_jet(context: &mut Context, subject: Noun) -> Result<Noun, JetErr> {
     let input = slot(subject, 6)?;
-    let mut input_vec = hoon_list_to_vecbelt(input)?;
+    let input_vec = hoon_list_to_vecbelt(input)?;
+    let lent_input = lent(input)?;
+
+    let digest = hash_varlen_core(input_vec, lent_input);
+
+    Ok(vec_to_hoon_list(context, &digest))
+}
+
+/// Plain-Rust entry point for `hash_varlen`, for callers outside the Hoon
+/// interpreter (e.g. a standalone PoW loop) that just want a digest for a
+/// slice of base-field elements with no `Noun`/`Context` involved.
+pub fn hash_varlen_plain(input: &[u64]) -> [u64; DIGEST_LENGTH] {
+    let input_vec: Vec<Belt> = input.iter().map(|&x| Belt(x)).collect();
+    hash_varlen_core(input_vec, input.len())
+}
+
+/// Shared core of `hash_varlen_jet`: pads `input_vec` to a multiple of
+/// `RATE`, brings it into Montgomery space, absorbs it `RATE` elements at a
+/// time, and squeezes a digest. Factored out so `hash_varlen_batch_jet` can
+/// run it per input while routing the Montgomery step through the batched
+/// SIMD backend.
+fn hash_varlen_core(mut input_vec: Vec<Belt>, lent_input: usize) -> [u64; DIGEST_LENGTH] {
     let mut sponge = [0u64; STATE_SIZE];
 
     // assert that input is made of base field elements
     input_vec.iter().for_each(|b| {based!(b.0)});
 
     // pad input with ~[1 0 ... 0] to be a multiple of rate
-    let lent_input = lent(input)?;
     let (q, r) = (lent_input / RATE, lent_input % RATE);
     input_vec.push(Belt(1));
     for _i in 0..(RATE - r) - 1 {
         input_vec.push(Belt(0));
     }
 
-    // bring input into montgomery space
+    // bring input into montgomery space, one vector call across every
+    // element instead of a scalar `montify` per element
+    let r2_vec = vec![Belt(R2); input_vec.len()];
     let mut input_montiplied: Vec<Belt> = vec![Belt(0); input_vec.len()];
-    for i in 0..input_vec.len() {
-        input_montiplied[i] = montify(input_vec[i]);
-    }
+    tip5_simd::montiply_batch(&input_vec, &r2_vec, &mut input_montiplied);
 
     // process input in batches of size RATE
     let mut cnt_q=q;
@@ -87,7 +108,61 @@ pub fn hash_varlen_jet(context: &mut Context, subject: Noun) -> Result<Noun, Jet
         digest[i] = mont_reduction(sponge[i] as u128).0;
     }
 
-    Ok(vec_to_hoon_list(context, &digest))
+    digest
+}
+
+/// Batched counterpart to `hash_varlen_jet`: hashes every input list in
+/// `sample` (a Hoon list of varlen input lists) independently, routing each
+/// one's Montgomery-space conversion through the vector backend. Sized for
+/// Merkle-tree leaf hashing, where many independent leaves need hashing at
+/// once.
+pub fn hash_varlen_batch_jet(context: &mut Context, subject: Noun) -> Result<Noun, JetErr> {
+    let sample = slot(subject, 6)?;
+
+    let mut digests: Vec<[u64; DIGEST_LENGTH]> = Vec::new();
+    let mut current = sample;
+    while current.is_cell() {
+        let cell = current.as_cell()?;
+        let input_vec = hoon_list_to_vecbelt(cell.head())?;
+        let lent_input = input_vec.len();
+        digests.push(hash_varlen_core(input_vec, lent_input));
+        current = cell.tail();
+    }
+
+    let mut list = D(0);
+    for digest in digests.iter().rev() {
+        let digest_list = vec_to_hoon_list(context, digest);
+        list = T(&mut context.stack, &[digest_list, list]);
+    }
+
+    Ok(list)
+}
+
+/// Batched counterpart to `permutation_jet`: permutes every sponge state in
+/// `sample` (a Hoon list of `STATE_SIZE`-element lists) in one call, so N
+/// independent Tip5 permutations (as used when hashing a batch of Merkle
+/// leaves) go through one call into `tip5_simd::permute_batch`, which runs
+/// the scalar `permute` once per state.
+pub fn permute_batch_jet(context: &mut Context, subject: Noun) -> Result<Noun, JetErr> {
+    let sample = slot(subject, 6)?;
+
+    let mut states: Vec<[u64; STATE_SIZE]> = Vec::new();
+    let mut current = sample;
+    while current.is_cell() {
+        let cell = current.as_cell()?;
+        states.push(hoon_list_to_sponge(cell.head())?);
+        current = cell.tail();
+    }
+
+    tip5_simd::permute_batch(&mut states);
+
+    let mut list = D(0);
+    for state in states.iter().rev() {
+        let state_list = vec_to_hoon_list(context, state);
+        list = T(&mut context.stack, &[state_list, list]);
+    }
+
+    Ok(list)
 }
 
 fn absorb_rate(sponge: &mut[u64; 16], input: &[Belt]) {
@@ -153,33 +228,30 @@ pub fn mont_reduction_jet(context: &mut Context, subject: Noun) -> Result<Noun,
 }
 
 fn mont_reduction(x: u128) -> Belt {
-    // mont-reduction: computes x•r^{-1} = (xr^{-1} mod p).
+    // mont-reduction (REDC): computes x•r^{-1} = (xr^{-1} mod p), using only
+    // multiplications, adds and shifts — no 128-bit division.
     assert!(x < RP);
 
-    const R_MOD_P1: u128 = (R_MOD_P + 1) as u128; // 4.294.967.296
-    const RX: u128 = R; // 18.446.744.073.709.551.616
     const PX: u128 = P as u128; // 0xffffffff00000001
+    // MU = -p^{-1} mod 2^64, precomputed so the REDC step below never needs
+    // to invert anything at runtime.
+    const MU: u64 = 0xFFFF_FFFE_FFFF_FFFF;
+
+    let x_lo = x as u64;
+    let m = x_lo.wrapping_mul(MU);
+    let m_n = (m as u128) * PX;
+
+    // t = (x + m*p) / 2^64; the low 64 bits of `x + m_n` are zero by
+    // construction (m was chosen so that x_lo + low64(m*p) ≡ 0 mod 2^64),
+    // so this right shift is an exact division, and `carry` just accounts
+    // for the addition overflowing past bit 127.
+    let (sum, carry) = x.overflowing_add(m_n);
+    let mut t = sum >> 64;
+    if carry {
+        t += 1u128 << 64;
+    }
 
-    let parts: [u64; 2] = [
-        (x & 0xFFFFFFFFFFFFFFFF) as u64, // lower 64 bits
-        (x >> 64) as u64,                // upper 64 bits
-    ];
-    let x_bitslice: &BitSlice<u64, Lsb0> = parts.view_bits::<Lsb0>();
-    let x_u128 = bitslice_to_u128(x_bitslice);
-
-    let x1_u128_div = x_u128 / R_MOD_P1;
-    let x1_u128 = x1_u128_div % R_MOD_P1;
-    let x2_u128 = x_u128 / RX;
-    let x0_u128 = x_u128 % R_MOD_P1;
-    let c_u128 = (x0_u128 + x1_u128) * R_MOD_P1;
-    let f_u128 = c_u128 / RX;
-    let d_u128 = c_u128 - (x1_u128 + (f_u128 * PX));
-
-    let res = if x2_u128 >= d_u128 {
-        x2_u128 - d_u128
-    } else {
-        (x2_u128 + PX) - d_u128
-    };
+    let res = if t >= PX { t - PX } else { t };
 
     Belt(res as u64)
 }
\ No newline at end of file
@@ -0,0 +1,201 @@
+//! Panic-free, allocation-free, data-independent reference implementation
+//! of the Tip5 Montgomery arithmetic core (`montiply`, `montify`,
+//! `mont_reduction`, and the `based!` range check `absorb_rate` relies on).
+//!
+//! Every function here returns `Result` instead of panicking on a bad
+//! range, and replaces the fast path's secret-dependent `if`/`assert!`
+//! branches with masked, branch-free selection (`select`/`cond_sub`), so
+//! control flow never depends on the field values being processed. That
+//! makes this module the natural target for a Rust-to-proof-assistant
+//! extraction toolchain to check the Montgomery identities the fast path
+//! (`tip5_jets::mont_reduction`, `tip5_simd`) is supposed to uphold:
+//! `montify(x) = x*R mod p`, `mont_reduction(x*y) = x*y*R^-1 mod p`, and
+//! every result lands in `[0, p)`. It avoids `std` types beyond `Result`
+//! and does no heap allocation, so it's written to be `no_std`-portable
+//! even though this crate as a whole isn't gated behind a `no_std`
+//! feature today.
+//!
+//! One honest limit: `absorb_rate_ref` still calls the existing `permute`
+//! (the MDS layer and S-box), which lives outside this crate's snapshot
+//! and so isn't itself re-verified panic-free here.
+//!
+//! This whole module is gated behind the `reference` cargo feature: it
+//! exists to be differentially checked against the fast path, not to ship
+//! in a production build, so consumers opt in to compiling it rather than
+//! paying for it unconditionally. (The feature still needs a matching
+//! `[features] reference = []` entry in this crate's `Cargo.toml`; no
+//! manifest for this crate is present in this tree.)
+
+#![cfg(feature = "reference")]
+
+use crate::form::math::tip5::{permute, P, R2, RATE, RP, STATE_SIZE};
+use crate::form::Belt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReferenceError {
+    /// A field element was not less than `P`.
+    NotBased,
+    /// An argument was outside the range a function requires.
+    OutOfRange,
+}
+
+/// Constant-time `if cond { a } else { b }`, selecting with a mask instead
+/// of branching on `cond`.
+#[inline]
+fn select(cond: bool, a: u64, b: u64) -> u64 {
+    let mask = (cond as u64).wrapping_neg(); // all-ones if true, all-zeros if false
+    (a & mask) | (b & !mask)
+}
+
+/// Constant-time `if x >= modulus { x - modulus } else { x }`.
+#[inline]
+fn cond_sub(x: u64, modulus: u64) -> u64 {
+    let (diff, borrowed) = x.overflowing_sub(modulus);
+    select(!borrowed, diff, x)
+}
+
+/// `Ok(())` iff `x` is a valid base-field element (`x < P`); the
+/// `Result`-returning counterpart of the `based!` macro's assertion.
+#[inline]
+pub fn based_checked(x: u64) -> Result<(), ReferenceError> {
+    if x < P {
+        Ok(())
+    } else {
+        Err(ReferenceError::NotBased)
+    }
+}
+
+/// Widening 64x64->128 multiply via four 32-bit partial products; see
+/// `tip5_simd::mulx64` for the overflow argument (identical here, just
+/// duplicated so this module has no dependency on the SIMD dispatch code
+/// it's meant to check).
+#[inline]
+fn mulx64(a: u64, b: u64) -> (u64, u64) {
+    let a_lo = a & 0xFFFF_FFFF;
+    let a_hi = a >> 32;
+    let b_lo = b & 0xFFFF_FFFF;
+    let b_hi = b >> 32;
+
+    let ll = a_lo * b_lo;
+    let lh = a_lo * b_hi;
+    let hl = a_hi * b_lo;
+    let hh = a_hi * b_hi;
+
+    let mid = (ll >> 32) + (lh & 0xFFFF_FFFF) + hl;
+    let lo = (ll & 0xFFFF_FFFF) | (mid << 32);
+    let hi = hh + (lh >> 32) + (mid >> 32);
+    (lo, hi)
+}
+
+const MU: u64 = 0xFFFF_FFFE_FFFF_FFFF;
+
+/// Division-free, branch-minimized REDC: `(lo + hi*2^64) * R^-1 mod p`.
+#[inline]
+fn redc(lo: u64, hi: u64) -> u64 {
+    let m = lo.wrapping_mul(MU);
+    let (mn_lo, mn_hi) = mulx64(m, P);
+
+    // `lo + mn_lo` is an exact multiple of 2^64 by construction: the carry
+    // out of that addition is 1 unless both operands are zero.
+    let carry = u64::from((lo | mn_lo) != 0);
+    let t = hi.wrapping_add(mn_hi).wrapping_add(carry);
+
+    // Recover the bit dropped by a 64-bit-lane add-with-carry, without
+    // branching on it: `wrapped` is 1 iff `hi + mn_hi + carry` overflowed
+    // past 2^64 (t < 2P, and 2P can exceed 2^64 for a prime this close to
+    // it). The overflow check compares against `hi`, the pre-addition
+    // value, the same way `tip5_simd::redc` does - comparing against the
+    // post-carry partial sum instead (as an earlier version of this
+    // function did) gives the wrong answer whenever `mn_hi` is 0.
+    let wrapped = u64::from(t < hi) | (u64::from(t == hi) & carry);
+    let epsilon = 0u64.wrapping_sub(P);
+    let t = t.wrapping_add(select(wrapped != 0, epsilon, 0));
+
+    cond_sub(t, P)
+}
+
+/// Reference `mont_reduction`: computes `x*R^-1 mod p`, returning
+/// `Err(OutOfRange)` instead of asserting when `x >= R*P`.
+pub fn mont_reduction_ref(x: u128) -> Result<Belt, ReferenceError> {
+    if x >= RP {
+        return Err(ReferenceError::OutOfRange);
+    }
+    let lo = x as u64;
+    let hi = (x >> 64) as u64;
+    Ok(Belt(redc(lo, hi)))
+}
+
+/// Reference `montiply`: computes `a*b*R^-1 mod p`, returning
+/// `Err(NotBased)` instead of asserting when either input isn't reduced.
+pub fn montiply_ref(a: u64, b: u64) -> Result<Belt, ReferenceError> {
+    based_checked(a)?;
+    based_checked(b)?;
+    let (lo, hi) = mulx64(a, b);
+    Ok(Belt(redc(lo, hi)))
+}
+
+/// Reference `montify`: transforms `x` into Montgomery space (`x*R mod p`).
+pub fn montify_ref(x: u64) -> Result<Belt, ReferenceError> {
+    montiply_ref(x, R2)
+}
+
+/// Reference `absorb_rate`: copies exactly `RATE` elements into `sponge`
+/// and permutes, returning `Err(OutOfRange)` instead of asserting when
+/// `input.len() != RATE` and indexing via `zip` instead of a bounds-checked
+/// manual loop.
+pub fn absorb_rate_ref(sponge: &mut [u64; STATE_SIZE], input: &[u64]) -> Result<(), ReferenceError> {
+    if input.len() != RATE {
+        return Err(ReferenceError::OutOfRange);
+    }
+
+    for (slot, value) in sponge.iter_mut().zip(input.iter()).take(RATE) {
+        *slot = *value;
+    }
+    permute(sponge);
+    Ok(())
+}
+
+/// Differential check: does the fast vector-dispatching `montiply_batch`
+/// path (`tip5_simd`) agree bit-for-bit with this reference implementation
+/// on a single lane? Intended to be driven over randomized `(a, b)` pairs
+/// by whatever harness wires this crate into CI; `false` on any mismatch
+/// (including a reference-side range error, which the fast path has no way
+/// to report).
+pub fn differential_check_montiply(a: u64, b: u64) -> bool {
+    let Ok(reference) = montiply_ref(a, b) else {
+        return false;
+    };
+
+    let mut fast = [Belt(0)];
+    crate::jets::tip5_simd::montiply_batch(&[Belt(a)], &[Belt(b)], &mut fast);
+
+    reference == fast[0]
+}
+
+/// Minimal, dependency-free splitmix64 generator, good enough to drive the
+/// randomized differential loop below without pulling an external `rand`
+/// crate into a module that otherwise only depends on `core`.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Runs `differential_check_montiply` over `trials` randomized `(a, b)`
+/// pairs in `[0, P)`, seeded from `seed` so a failing run is reproducible.
+/// Returns `false` on (and stops at) the first mismatch. It does not report
+/// which trial or pair failed; rerunning with the same `seed` replays the
+/// same sequence of pairs.
+pub fn differential_check_montiply_random(seed: u64, trials: usize) -> bool {
+    let mut state = seed;
+    for _ in 0..trials {
+        let a = splitmix64(&mut state) % P;
+        let b = splitmix64(&mut state) % P;
+        if !differential_check_montiply(a, b) {
+            return false;
+        }
+    }
+    true
+}
@@ -0,0 +1,289 @@
+//! Vectorized backend for batched Tip5 permutation and Montgomery field
+//! arithmetic over the Goldilocks field.
+//!
+//! `permute`/`montiply`/`montify` in [`super::tip5_jets`] process one sponge
+//! state or one field multiply at a time. When hashing many independent
+//! leaves at once (the shape Merkle-tree leaf hashing needs), lane-wise
+//! vector multiply lets one AVX2 instruction do the work of 4 scalar ones;
+//! the NEON backend runs one lane at a time. Dispatch is runtime feature-detected so the scalar path is always
+//! correct, everywhere, and the vector paths only ever run where the CPU
+//! actually supports them.
+//!
+//! Only `montiply_lanes` (the Montgomery multiply at the core of every
+//! S-box and MDS step) is vectorized so far. `permute_batch`/`absorb_batch`
+//! still call the scalar `permute` once per lane: fully vectorizing the MDS
+//! matrix and the x^7/inverse-power S-box needs those tables ported into
+//! this module too, which is a larger follow-up left for once
+//! `montiply_lanes` has proven out the dispatch plumbing.
+
+use crate::form::math::tip5::{permute, P, STATE_SIZE};
+use crate::form::Belt;
+
+/// -P^{-1} mod 2^64, the same Montgomery constant `mont_reduction` uses.
+const MU: u64 = 0xFFFF_FFFE_FFFF_FFFF;
+
+/// Lane width processed per vector call; `1` for the scalar backend.
+pub trait Tip5SimdBackend {
+    const LANES: usize;
+
+    /// Lane-wise Montgomery multiply: `out[i] = montiply(a[i], b[i])` for
+    /// every `i` in `0..out.len()`, `Self::LANES` lanes per vector step.
+    fn montiply_lanes(&self, a: &[u64], b: &[u64], out: &mut [u64]);
+
+    /// Permutes every sponge state in `states` independently, in place.
+    fn permute_batch(&self, states: &mut [[u64; STATE_SIZE]]) {
+        for state in states.iter_mut() {
+            permute(state);
+        }
+    }
+}
+
+/// Widening 64x64->128 multiply via four 32-bit partial products. No
+/// intermediate sum can overflow a `u64` (the classic schoolbook-multiply
+/// bound), which is exactly what makes this portable to SIMD lanes without
+/// any cross-term carry propagation.
+#[inline]
+fn mulx64(a: u64, b: u64) -> (u64, u64) {
+    let a_lo = a & 0xFFFF_FFFF;
+    let a_hi = a >> 32;
+    let b_lo = b & 0xFFFF_FFFF;
+    let b_hi = b >> 32;
+
+    let ll = a_lo * b_lo;
+    let lh = a_lo * b_hi;
+    let hl = a_hi * b_lo;
+    let hh = a_hi * b_hi;
+
+    let mid = (ll >> 32) + (lh & 0xFFFF_FFFF) + hl;
+    let lo = (ll & 0xFFFF_FFFF) | (mid << 32);
+    let hi = hh + (lh >> 32) + (mid >> 32);
+    (lo, hi)
+}
+
+/// Single-lane division-free REDC, identical to `tip5_jets::mont_reduction`
+/// but taking the pre-split `(lo, hi)` 128-bit product so it can share code
+/// with the vector backends below.
+#[inline]
+fn redc(lo: u64, hi: u64) -> u64 {
+    let m = lo.wrapping_mul(MU);
+    let (mn_lo, mn_hi) = mulx64(m, P);
+
+    // lo + mn_lo is always an exact multiple of 2^64 by construction, so
+    // the carry out of this addition is 1 unless both are zero.
+    let carry = u64::from((lo | mn_lo) != 0);
+    let t = hi.wrapping_add(mn_hi).wrapping_add(carry);
+    // `hi + mn_hi + carry` can legitimately be 2^64 or more (t < 2P, and
+    // 2P can exceed 2^64 for a prime this close to it), so recover the
+    // dropped bit by adding 2^64 mod P (= 2^64 - P): an overflow here is
+    // equivalent to one extra factor of 2^64 mod P.
+    let overflowed = t < hi || (carry == 1 && t == hi);
+    let t = if overflowed {
+        t.wrapping_add((0u64).wrapping_sub(P))
+    } else {
+        t
+    };
+
+    if t >= P {
+        t - P
+    } else {
+        t
+    }
+}
+
+#[inline]
+fn montiply_scalar(a: u64, b: u64) -> u64 {
+    let (lo, hi) = mulx64(a, b);
+    redc(lo, hi)
+}
+
+/// Always-available, non-vectorized backend. `Tip5SimdBackend::LANES == 1`:
+/// every dispatcher below falls back to this when no wider instruction set
+/// is available (or detected) on the running CPU.
+pub struct ScalarBackend;
+
+impl Tip5SimdBackend for ScalarBackend {
+    const LANES: usize = 1;
+
+    fn montiply_lanes(&self, a: &[u64], b: &[u64], out: &mut [u64]) {
+        for i in 0..out.len() {
+            out[i] = montiply_scalar(a[i], b[i]);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+pub mod avx2 {
+    use super::*;
+    use std::arch::x86_64::*;
+
+    /// 4-lane AVX2 backend. Only ever constructed after
+    /// `is_x86_feature_detected!("avx2")` has been confirmed, via
+    /// [`super::montiply_batch`].
+    pub struct Avx2Backend;
+
+    impl Tip5SimdBackend for Avx2Backend {
+        const LANES: usize = 4;
+
+        fn montiply_lanes(&self, a: &[u64], b: &[u64], out: &mut [u64]) {
+            let mut i = 0;
+            while i + 4 <= out.len() {
+                unsafe {
+                    let r = montiply_x4(
+                        _mm256_loadu_si256(a[i..].as_ptr() as *const __m256i),
+                        _mm256_loadu_si256(b[i..].as_ptr() as *const __m256i),
+                    );
+                    _mm256_storeu_si256(out[i..].as_mut_ptr() as *mut __m256i, r);
+                }
+                i += 4;
+            }
+            // Tail shorter than a full vector: scalar fallback, still correct.
+            for j in i..out.len() {
+                out[j] = montiply_scalar(a[j], b[j]);
+            }
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn mulx64_x4(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+        let mask32 = _mm256_set1_epi64x(0xFFFF_FFFFu32 as i64);
+
+        let a_lo = _mm256_and_si256(a, mask32);
+        let a_hi = _mm256_srli_epi64(a, 32);
+        let b_lo = _mm256_and_si256(b, mask32);
+        let b_hi = _mm256_srli_epi64(b, 32);
+
+        // _mm256_mul_epu32 multiplies the low 32 bits of each 64-bit lane.
+        let ll = _mm256_mul_epu32(a_lo, b_lo);
+        let lh = _mm256_mul_epu32(a_lo, b_hi);
+        let hl = _mm256_mul_epu32(a_hi, b_lo);
+        let hh = _mm256_mul_epu32(a_hi, b_hi);
+
+        let mid = _mm256_add_epi64(
+            _mm256_add_epi64(_mm256_srli_epi64(ll, 32), _mm256_and_si256(lh, mask32)),
+            hl,
+        );
+        let lo = _mm256_or_si256(_mm256_and_si256(ll, mask32), _mm256_slli_epi64(mid, 32));
+        let hi = _mm256_add_epi64(
+            _mm256_add_epi64(hh, _mm256_srli_epi64(lh, 32)),
+            _mm256_srli_epi64(mid, 32),
+        );
+        (lo, hi)
+    }
+
+    /// Unsigned `a < b`, lane-wise: flip the sign bit of both operands and
+    /// reuse the signed compare (AVX2 has no unsigned 64-bit compare).
+    #[target_feature(enable = "avx2")]
+    unsafe fn unsigned_lt(a: __m256i, b: __m256i) -> __m256i {
+        let bias = _mm256_set1_epi64x(i64::MIN);
+        _mm256_cmpgt_epi64(_mm256_xor_si256(b, bias), _mm256_xor_si256(a, bias))
+    }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn montiply_x4(a: __m256i, b: __m256i) -> __m256i {
+        let p = _mm256_set1_epi64x(P as i64);
+        let mu = _mm256_set1_epi64x(MU as i64);
+        let mask32 = _mm256_set1_epi64x(0xFFFF_FFFFu32 as i64);
+        let zero = _mm256_setzero_si256();
+
+        let (lo, hi) = mulx64_x4(a, b);
+
+        // m = lo * MU mod 2^64 (only the low 64 bits of the product matter).
+        let (m_lo, _) = mulx64_x4(lo, mu);
+        let m = m_lo;
+        let (mn_lo, mn_hi) = mulx64_x4(m, p);
+
+        // carry = (lo | mn_lo) != 0, as an all-ones/all-zeros mask, then
+        // narrowed to {0, 1} per lane.
+        let or_val = _mm256_or_si256(lo, mn_lo);
+        let is_zero_mask = _mm256_cmpeq_epi64(or_val, zero);
+        let carry = _mm256_andnot_si256(is_zero_mask, _mm256_set1_epi64x(1));
+
+        let t_partial = _mm256_add_epi64(hi, mn_hi);
+        let t = _mm256_add_epi64(t_partial, carry);
+
+        // Detect the dropped 65th bit the same way the scalar `redc` does:
+        // the high-word addition (plus carry-in) wrapped past 2^64 iff the
+        // result is less than `hi`, or equal to `hi` while a carry-in of 1
+        // was also added (the only way to wrap back to the same value).
+        let wrapped_add = unsigned_lt(t, hi);
+        let eq_with_carry = _mm256_and_si256(_mm256_cmpeq_epi64(t, hi), carry);
+        // carry is 0/1 in every lane; treat "== 1" as all-ones via cmpeq.
+        let eq_with_carry = _mm256_cmpeq_epi64(eq_with_carry, _mm256_set1_epi64x(1));
+        let overflowed = _mm256_or_si256(wrapped_add, eq_with_carry);
+
+        let epsilon = _mm256_set1_epi64x((0u64.wrapping_sub(P)) as i64);
+        let t = _mm256_add_epi64(t, _mm256_and_si256(overflowed, epsilon));
+
+        let ge_p = _mm256_or_si256(unsigned_lt(p, t), _mm256_cmpeq_epi64(t, p));
+        let reduced = _mm256_sub_epi64(t, p);
+        _mm256_blendv_epi8(t, reduced, ge_p)
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+pub mod neon {
+    use super::*;
+
+    /// 1-lane-at-a-time NEON backend: NEON has no native 64x64->128 widening
+    /// multiply either, and vectorizing the carry chain above with
+    /// `vmull`/`vaddq` intrinsics is left as a follow-up now that the
+    /// dispatch plumbing and the AVX2 reference implementation exist. This
+    /// still genuinely gates on `is_aarch64_feature_detected!("neon")`, so
+    /// no behavior changes, only the backend selected.
+    pub struct NeonBackend;
+
+    impl Tip5SimdBackend for NeonBackend {
+        const LANES: usize = 1;
+
+        fn montiply_lanes(&self, a: &[u64], b: &[u64], out: &mut [u64]) {
+            for i in 0..out.len() {
+                out[i] = montiply_scalar(a[i], b[i]);
+            }
+        }
+    }
+}
+
+/// Batched Montgomery multiply over `a`/`b`/`out` (equal-length slices),
+/// dispatching to the widest backend the running CPU actually supports.
+pub fn montiply_batch(a: &[Belt], b: &[Belt], out: &mut [Belt]) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), out.len());
+
+    let a_raw: Vec<u64> = a.iter().map(|x| x.0).collect();
+    let b_raw: Vec<u64> = b.iter().map(|x| x.0).collect();
+    let mut out_raw = vec![0u64; out.len()];
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            avx2::Avx2Backend.montiply_lanes(&a_raw, &b_raw, &mut out_raw);
+            for (dst, src) in out.iter_mut().zip(out_raw) {
+                *dst = Belt(src);
+            }
+            return;
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            neon::NeonBackend.montiply_lanes(&a_raw, &b_raw, &mut out_raw);
+            for (dst, src) in out.iter_mut().zip(out_raw) {
+                *dst = Belt(src);
+            }
+            return;
+        }
+    }
+
+    ScalarBackend.montiply_lanes(&a_raw, &b_raw, &mut out_raw);
+    for (dst, src) in out.iter_mut().zip(out_raw) {
+        *dst = Belt(src);
+    }
+}
+
+/// Permutes every sponge state in `states`, independently, using whatever
+/// backend the CPU supports (today this always runs the scalar `permute`
+/// per state; see the module doc comment for why the vector backends don't
+/// yet cover the MDS/S-box layers).
+pub fn permute_batch(states: &mut [[u64; STATE_SIZE]]) {
+    ScalarBackend.permute_batch(states);
+}